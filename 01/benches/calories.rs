@@ -0,0 +1,15 @@
+use advent::{bench::Bencher, input::normalize};
+use calories::{balance, parse_elves, stream, Elves, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("parse_elves", || parse_elves(&input).unwrap());
+    let elves = Elves::new(parse_elves(&input).unwrap());
+    bencher.bench("Elves::top_n", || elves.top_n(3));
+    bencher.bench("stream::top_k", || {
+        stream::top_k(input.as_bytes(), 3).unwrap()
+    });
+    bencher.bench("balance::balance", || balance::balance(&elves));
+}
@@ -0,0 +1,294 @@
+//! Evening out what the elves carry by handing snacks between them.
+//!
+//! Finding the smallest possible spread is multiway number partitioning,
+//! which is NP-hard, so [`balance`] searches locally instead: it hands a
+//! snack, or swaps two, between the fullest or emptiest elf and another for
+//! as long as that brings the pair closer together. It starts once from the
+//! elves' own packs and once from a greedy repacking, and keeps whichever
+//! ends with the smaller spread.
+
+use std::{cmp::Reverse, collections::BTreeMap, fmt::Display};
+
+use crate::Elves;
+
+/// A snack handed from one elf to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub calories: u64,
+    pub from: usize,
+    /// Where the snack was in `from`'s pack, counting from 0.
+    pub snack: usize,
+    pub to: usize,
+}
+
+/// The snacks to move, and the spread between the fullest and emptiest elf
+/// before and after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub moves: Vec<Move>,
+    pub before: u64,
+    pub after: u64,
+    /// Each elf's total once the moves are made.
+    pub totals: Vec<u64>,
+}
+
+/// Every snack with the elf it starts with.
+struct Snack {
+    elf: usize,
+    index: usize,
+    calories: u64,
+}
+
+enum Change {
+    Hand { snack: usize, to: usize },
+    Swap { a: usize, b: usize },
+}
+
+/// Each elf's total when snack `i` is carried by `owners[i]`.
+fn totals(snacks: &[Snack], owners: &[usize], elves: usize) -> Vec<u64> {
+    let mut totals = vec![0; elves];
+    for (snack, &owner) in snacks.iter().zip(owners) {
+        totals[owner] += snack.calories;
+    }
+    totals
+}
+
+fn spread(totals: &[u64]) -> u64 {
+    let max = totals.iter().max().copied().unwrap_or(0);
+    let min = totals.iter().min().copied().unwrap_or(0);
+    max - min
+}
+
+/// Improves `owners` until no hand or swap between the fullest or emptiest
+/// elf and another brings the two closer.
+///
+/// Sending `d` calories from an elf with `g` to one with `t` is only taken
+/// when `0 < d < g - t`, so both end up strictly between their old totals:
+/// the spread never grows and the sum of squared totals always shrinks,
+/// which is why this stops.
+fn improve(snacks: &[Snack], owners: &mut [usize], elves: usize) {
+    loop {
+        let totals = totals(snacks, owners, elves);
+        let mut packs = vec![Vec::new(); elves];
+        for (i, &owner) in owners.iter().enumerate() {
+            packs[owner].push(i);
+        }
+        let fullest = (0..elves).max_by_key(|&e| (totals[e], usize::MAX - e));
+        let emptiest = (0..elves).min_by_key(|&e| (totals[e], e));
+        let (Some(fullest), Some(emptiest)) = (fullest, emptiest) else {
+            return;
+        };
+
+        let pairs = (0..elves)
+            .map(|to| (fullest, to))
+            .chain((0..elves).map(|from| (from, emptiest)));
+        // The change that most shrinks the sum of squared totals, by how much.
+        let mut best: Option<(u128, Change)> = None;
+        let mut consider = |d: u64, gap: u64, change: Change| {
+            if 0 < d && d < gap {
+                let gain = d as u128 * (gap - d) as u128;
+                if best.as_ref().is_none_or(|(most, _)| gain > *most) {
+                    best = Some((gain, change));
+                }
+            }
+        };
+        for (from, to) in pairs {
+            let Some(gap) = totals[from].checked_sub(totals[to]) else {
+                continue;
+            };
+            for &a in packs[from].iter() {
+                consider(snacks[a].calories, gap, Change::Hand { snack: a, to });
+                for &b in packs[to].iter() {
+                    if let Some(d) = snacks[a].calories.checked_sub(snacks[b].calories) {
+                        consider(d, gap, Change::Swap { a, b });
+                    }
+                }
+            }
+        }
+
+        match best {
+            None => return,
+            Some((_, Change::Hand { snack, to })) => owners[snack] = to,
+            Some((_, Change::Swap { a, b })) => owners.swap(a, b),
+        }
+    }
+}
+
+/// Packs the largest snacks first, each to whoever carries least so far, or
+/// nothing if a total would overflow.
+fn repack(snacks: &[Snack], elves: usize) -> Option<Vec<usize>> {
+    let mut order: Vec<usize> = (0..snacks.len()).collect();
+    order.sort_by_key(|&i| Reverse(snacks[i].calories));
+
+    let mut owners = vec![0; snacks.len()];
+    let mut totals = vec![0u64; elves];
+    for i in order {
+        let elf = (0..elves).min_by_key(|&e| (totals[e], e))?;
+        totals[elf] = totals[elf].checked_add(snacks[i].calories)?;
+        owners[i] = elf;
+    }
+    Some(owners)
+}
+
+/// Renames the elves in `owners`, which balances just as well under any
+/// names, so that as many snacks as it can stay with the elf they started with.
+fn keep_in_place(snacks: &[Snack], owners: &mut [usize], elves: usize) {
+    // How many snacks each elf of `owners` shares with each starting elf.
+    let mut shared: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for (snack, &owner) in snacks.iter().zip(owners.iter()) {
+        *shared.entry((owner, snack.elf)).or_default() += 1;
+    }
+    let mut pairs: Vec<((usize, usize), usize)> = shared.into_iter().collect();
+    pairs.sort_by_key(|&(pair, n)| (Reverse(n), pair));
+
+    let mut names: Vec<Option<usize>> = vec![None; elves];
+    let mut taken = vec![false; elves];
+    for ((owner, elf), _) in pairs {
+        if names[owner].is_none() && !taken[elf] {
+            names[owner] = Some(elf);
+            taken[elf] = true;
+        }
+    }
+    let mut free = (0..elves).filter(|&elf| !taken[elf]);
+    let names: Vec<usize> = names
+        .into_iter()
+        .map(|name| name.or_else(|| free.next()).unwrap_or_default())
+        .collect();
+    for owner in owners.iter_mut() {
+        *owner = names[*owner];
+    }
+}
+
+/// A plan narrowing the spread of the elves' totals, moving whole snacks;
+/// see the [module docs](self) for how, and why it may not be the best.
+pub fn balance(elves: &Elves) -> Plan {
+    let count = elves.elves().len();
+    let snacks: Vec<Snack> = elves
+        .elves()
+        .iter()
+        .flat_map(|elf| {
+            elf.snacks()
+                .iter()
+                .enumerate()
+                .map(|(index, &calories)| Snack {
+                    elf: elf.index,
+                    index,
+                    calories,
+                })
+        })
+        .collect();
+    let start: Vec<usize> = snacks.iter().map(|snack| snack.elf).collect();
+    let before = spread(&totals(&snacks, &start, count));
+
+    // How good a packing is: its spread, then how many snacks it moves.
+    let cost = |owners: &[usize]| {
+        let moved = owners.iter().zip(start.iter()).filter(|(a, b)| a != b);
+        (spread(&totals(&snacks, owners, count)), moved.count())
+    };
+
+    let mut owners = start.clone();
+    improve(&snacks, &mut owners, count);
+    if let Some(mut repacked) = repack(&snacks, count) {
+        improve(&snacks, &mut repacked, count);
+        keep_in_place(&snacks, &mut repacked, count);
+        if cost(&repacked) < cost(&owners) {
+            owners = repacked;
+        }
+    }
+
+    let moves = snacks
+        .iter()
+        .zip(owners.iter())
+        .filter(|(snack, &to)| snack.elf != to)
+        .map(|(snack, &to)| Move {
+            calories: snack.calories,
+            from: snack.elf,
+            snack: snack.index,
+            to,
+        })
+        .collect();
+    let totals = totals(&snacks, &owners, count);
+    Plan {
+        moves,
+        before,
+        after: spread(&totals),
+        totals,
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let moves = match self.moves.len() {
+            1 => "1 move".to_string(),
+            n => format!("{n} moves"),
+        };
+        write!(f, "spread {} -> {} with {moves}", self.before, self.after)?;
+        for m in self.moves.iter() {
+            write!(
+                f,
+                "\n{:>7} calories: elf {} (snack {}) -> elf {}",
+                m.calories,
+                m.from + 1,
+                m.snack + 1,
+                m.to + 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{assert_snapshot, random::Rng, Solution};
+
+    use crate::{
+        balance::{balance, Plan},
+        reference, Elves,
+    };
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    /// The totals after making `plan`'s moves on `elves`, checking each move
+    /// takes a snack its elf really has.
+    fn apply(elves: &Elves, plan: &Plan) -> Vec<u64> {
+        let mut totals: Vec<u64> = elves.elves().iter().map(|elf| elf.total()).collect();
+        for m in plan.moves.iter() {
+            assert_eq!(elves.elves()[m.from].snacks()[m.snack], m.calories);
+            totals[m.from] -= m.calories;
+            totals[m.to] += m.calories;
+        }
+        totals
+    }
+
+    #[test]
+    fn evens_out_the_example() {
+        let elves = Elves::parse(EXAMPLE).unwrap();
+        let plan = balance(&elves);
+        assert_eq!((plan.before, plan.after), (20000, 0));
+        assert_eq!(plan.totals, [11000; 5]);
+        assert_eq!(apply(&elves, &plan), plan.totals);
+        assert_snapshot!("example_balance", plan);
+    }
+
+    #[test]
+    fn keeps_snacks_whole() {
+        let plan = balance(&Elves::parse("10\n\n1").unwrap());
+        assert_eq!((plan.before, plan.after), (9, 9));
+        assert!(plan.moves.is_empty());
+
+        let plan = balance(&Elves::parse("5\n\n5").unwrap());
+        assert_eq!((plan.after, plan.moves.len()), (0, 0));
+        assert_eq!(balance(&Elves::parse("").unwrap()).after, 0);
+    }
+
+    #[test]
+    fn never_widens() {
+        for seed in 0..50 {
+            let input = reference::generate(&mut Rng::new(seed));
+            let elves = Elves::parse(&input).unwrap();
+            let plan = balance(&elves);
+            assert!(plan.after <= plan.before, "seed {seed}");
+            assert_eq!(apply(&elves, &plan), plan.totals, "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use advent::{parse, trace, validate::Validate, ParseError, Solution};
+use stream::TopK;
+
+pub mod balance;
+pub mod reference;
+pub mod stats;
+pub mod stream;
+
+pub const INPUT: &str = include_str!("../data");
+
+/// One elf's inventory: the calories of each snack, as listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Where the elf comes in the input, counting from 0.
+    pub index: usize,
+    snacks: Vec<u64>,
+    total: u64,
+}
+
+impl Elf {
+    pub fn snacks(&self) -> &[u64] {
+        &self.snacks
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// Parses a snack from `line` and adds it to the running `total` of the elf
+/// at `index`, failing rather than wrapping if it will not fit.
+pub fn add_snack(total: &mut u64, line: &str, index: usize) -> Result<u64, ParseError> {
+    let calories: u64 = parse::int(line, "a calorie count")?;
+    *total = total.checked_add(calories).ok_or_else(|| {
+        let elf = index + 1;
+        ParseError::new(
+            line,
+            format!(
+                "a calorie count that keeps elf {elf}'s total within {}",
+                u64::MAX
+            ),
+        )
+    })?;
+    Ok(calories)
+}
+
+/// Every elf in input order, the last one whether or not a blank line follows it.
+pub fn parse_elves(input: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut index = 0;
+    parse::records(input, |elf| {
+        let mut total = 0;
+        let snacks = parse::lines(elf, |line| {
+            trace!("parsing `{line}`");
+            add_snack(&mut total, line, index)
+        })?;
+        let elf = Elf {
+            index,
+            snacks,
+            total,
+        };
+        index += 1;
+        Ok(elf)
+    })
+}
+
+/// Calories carried between `elves`, which cannot overflow: there are
+/// fewer than 2^64 of them, each carrying less than 2^64.
+pub fn total(elves: &[&Elf]) -> u128 {
+    elves.iter().map(|elf| elf.total() as u128).sum()
+}
+
+/// The elves of an expedition.
+pub struct Elves(Vec<Elf>);
+
+impl Elves {
+    pub fn new(elves: Vec<Elf>) -> Self {
+        Elves(elves)
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.0
+    }
+
+    /// The `n` elves carrying the most calories, most first, or every elf if
+    /// there are fewer. Ties go to the elf listed first.
+    pub fn top_n(&self, n: usize) -> Vec<&Elf> {
+        let mut top = TopK::new(n);
+        for elf in self.0.iter() {
+            top.push(elf.index, elf.total());
+        }
+        top.into_sorted()
+            .iter()
+            .map(|ranked| &self.0[ranked.index])
+            .collect()
+    }
+}
+
+impl Solution for Elves {
+    type PartOne = u64;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Elves::new(parse_elves(input)?))
+    }
+
+    fn part_one(&self) -> u64 {
+        self.top_n(1).first().map_or(0, |elf| elf.total())
+    }
+
+    fn part_two(&self) -> u128 {
+        total(&self.top_n(3))
+    }
+}
+
+impl Validate for Elves {
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+        let record = |e| {
+            errors.push(e);
+            Ok(())
+        };
+        stream::read_log(input.as_bytes(), |_, _| {}, record)
+            .expect("reading a log already in memory cannot fail");
+        errors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{validate::Validate, Solution};
+
+    use crate::{parse_elves, total, Elves};
+
+    const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn example() {
+        let elves = Elves::parse(EXAMPLE).unwrap();
+        assert_eq!(elves.part_one(), 24000);
+        assert_eq!(elves.part_two(), 45000);
+    }
+
+    #[test]
+    fn keeps_the_last_elf() {
+        let totals = |input| -> Vec<u64> {
+            parse_elves(input)
+                .unwrap()
+                .iter()
+                .map(|elf| elf.total())
+                .collect()
+        };
+        assert_eq!(totals("1\n2\n\n3"), [3, 3]);
+        assert_eq!(totals("1\n2\n\n3\n"), [3, 3]);
+        assert_eq!(totals("1\n2\n\n3\n\n\n"), [3, 3]);
+    }
+
+    #[test]
+    fn top_n() {
+        let elves = Elves::parse(EXAMPLE).unwrap();
+        let ranked: Vec<usize> = elves.top_n(10).iter().map(|elf| elf.index).collect();
+        assert_eq!(ranked, [3, 2, 4, 0, 1]);
+        assert_eq!(total(&elves.top_n(0)), 0);
+        assert_eq!(total(&elves.top_n(5)), 55000);
+        assert_eq!(elves.top_n(usize::MAX).len(), 5);
+
+        let ties = Elves::parse("5\n\n7\n\n2\n3").unwrap();
+        let ranked: Vec<usize> = ties.top_n(2).iter().map(|elf| elf.index).collect();
+        assert_eq!(ranked, [1, 0]);
+    }
+
+    #[test]
+    fn fewer_than_three_elves() {
+        let elves = Elves::parse("100\n200").unwrap();
+        assert_eq!(elves.part_one(), 300);
+        assert_eq!(elves.part_two(), 300);
+
+        let nobody = Elves::parse("").unwrap();
+        assert_eq!(nobody.part_one(), 0);
+        assert_eq!(nobody.part_two(), 0);
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let input = format!("1\n\n{}\n2\n\n3", u64::MAX - 1);
+        let error = parse_elves(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected a calorie count that keeps elf 2's total within 18446744073709551615, found `2`"
+        );
+
+        let input = format!("{}\n\n{}\n\n{}", u64::MAX, u64::MAX, u64::MAX);
+        let elves = Elves::parse(&input).unwrap();
+        assert_eq!(elves.part_one(), u64::MAX);
+        assert_eq!(elves.part_two(), u64::MAX as u128 * 3);
+    }
+
+    #[test]
+    fn validates_snacks() {
+        let input = format!("1\nx\n\n{}\n  2\n\n3\n-4\n", u64::MAX);
+        let errors = Elves::validate(&input);
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(found, [(2, 1), (5, 3), (8, 1)]);
+        assert_eq!(
+            errors[1].expected,
+            "a calorie count that keeps elf 2's total within 18446744073709551615"
+        );
+        assert!(Elves::validate(EXAMPLE).is_empty());
+    }
+}
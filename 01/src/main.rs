@@ -1,27 +1,102 @@
-use std::io::stdin;
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut elves = Vec::<u32>::new();
-    let mut meal = 0u32;
-    let input = &mut String::new();
-
-    while stdin().read_line(input)? > 0 {
-        if matches!(input.as_str(), "\n") {
-            elves.push(meal);
-            meal = 0;
-        } else {
-            let input = input.trim();
-            eprintln!("Parsing: `{input}`");
-            meal += input.parse::<u32>()?;
+use std::{
+    error::Error,
+    fs::File,
+    io::{stdin, BufReader},
+    process::ExitCode,
+};
+
+use advent::input::Source;
+use calories::{balance, parse_elves, stats::Stats, stream, Elves, INPUT};
+
+const USAGE: &str = "usage: calories [PATH|-] [--part 1|2] [--format text|json|csv]
+       calories --top K [PATH|-]
+       calories --stats [--top K] [--json] [PATH|-]
+       calories --balance [PATH|-]";
+
+/// Streams a log of any size, the embedded one by default, printing the `K`
+/// elves carrying the most.
+fn top(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let (k, path) = match args {
+        [k] => (k, None),
+        [k, path] => (k, Some(path.as_str())),
+        _ => return Err(USAGE.into()),
+    };
+    let k = count(k)?;
+    let ranked = match Source::from_arg(path) {
+        Source::Embedded => stream::top_k(INPUT.as_bytes(), k)?,
+        Source::Stdin => stream::top_k(stdin().lock(), k)?,
+        Source::File(path) => {
+            let file = File::open(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+            stream::top_k(BufReader::new(file), k)?
         }
-        input.clear();
+    };
+
+    for (rank, elf) in ranked.iter().enumerate() {
+        println!("{:>3}. elf {:<6} {}", rank + 1, elf.index + 1, elf.total);
     }
+    let total: u128 = ranked.iter().map(|elf| elf.total as u128).sum();
+    println!("total {total}");
+    Ok(())
+}
+
+/// Reads the whole input, the embedded one by default, and reports on it.
+fn stats(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut source = None;
+    let mut top = 3;
+    let mut json = false;
 
-    elves.sort();
-    elves.reverse();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--top" => top = count(args.next().ok_or(USAGE)?)?,
+            _ if source.is_none() && (arg == "-" || !arg.starts_with('-')) => source = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}").into()),
+        }
+    }
 
-    println!("{}", elves[0]);
-    println!("{}", elves[0] + elves[1] + elves[2]);
+    let input = Source::from_arg(source.map(String::as_str)).read(INPUT)?;
+    let elves = Elves::new(parse_elves(&input).map_err(|e| e.report(&input))?);
+    let stats = Stats::new(&elves, top).ok_or("no elves in the input")?;
+    if json {
+        println!("{}", stats.json());
+    } else {
+        println!("{stats}");
+    }
+    Ok(())
+}
 
+/// Prints the snacks to hand around so the elves carry about the same.
+fn balance(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let source = match args {
+        [] => None,
+        [path] => Some(path.as_str()),
+        _ => return Err(USAGE.into()),
+    };
+    let input = Source::from_arg(source).read(INPUT)?;
+    let elves = Elves::new(parse_elves(&input).map_err(|e| e.report(&input))?);
+    println!("{}", balance::balance(&elves));
     Ok(())
 }
+
+fn count(k: &str) -> Result<usize, String> {
+    k.parse()
+        .map_err(|_| format!("K must be a number\n{USAGE}"))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((flag, rest)) if flag == "--top" => top(rest),
+        Some((flag, rest)) if flag == "--stats" => stats(rest),
+        Some((flag, rest)) if flag == "--balance" => balance(rest),
+        _ => return advent::run::<Elves>(1, INPUT),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Random calorie lists, and their top totals found by picking the largest
+//! again and again.
+
+use advent::random::Rng;
+
+/// Elves separated by blank lines, each carrying one to six snacks.
+pub fn generate(rng: &mut Rng) -> String {
+    let elves: Vec<String> = (0..rng.usize(3..=20))
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.usize(1..=6))
+                .map(|_| rng.usize(1..=60_000).to_string())
+                .collect();
+            snacks.join("\n")
+        })
+        .collect();
+    elves.join("\n\n")
+}
+
+fn totals(input: &str) -> Vec<u64> {
+    let mut totals = vec![0];
+    for line in input.lines() {
+        if line.is_empty() {
+            totals.push(0);
+        } else {
+            *totals.last_mut().unwrap() += line.parse::<u64>().unwrap();
+        }
+    }
+    totals
+}
+
+pub fn part_one(input: &str) -> u64 {
+    totals(input).into_iter().max().unwrap()
+}
+
+/// Takes the largest total three times over.
+pub fn part_two(input: &str) -> u128 {
+    let mut totals = totals(input);
+    let mut sum = 0;
+    for _ in 0..3 {
+        let (i, _) = totals.iter().enumerate().max_by_key(|(_, t)| **t).unwrap();
+        sum += totals.remove(i) as u128;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Elves};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Elves>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! A summary of what the expedition carries, as a table or JSON.
+//!
+//! Elves and snacks are numbered from 1 in the report, as the puzzle counts them.
+
+use std::fmt::Display;
+
+use crate::{stream::Ranked, Elves};
+
+/// The percentiles of elf totals reported, besides the median.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Roughly how many histogram buckets to draw.
+const BUCKETS: u64 = 10;
+
+/// The widest histogram bar, in characters.
+const BAR: usize = 40;
+
+/// The largest single snack, and who carries it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snack {
+    pub elf: usize,
+    pub snack: usize,
+    pub calories: u64,
+}
+
+/// How many elves carry between `from` and `to` calories, inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+/// How the elves' totals are spread, and who carries the most.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub snacks: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Each of [`PERCENTILES`] with its total, by nearest rank.
+    pub percentiles: Vec<(u8, u64)>,
+    pub largest_snack: Option<Snack>,
+    pub histogram: Vec<Bucket>,
+    pub top: Vec<Ranked>,
+}
+
+/// The smallest of 1, 2 or 5 times a power of ten that is at least `at_least`.
+fn round_width(at_least: u64) -> u64 {
+    let mut scale = 1;
+    loop {
+        for step in [1, 2, 5] {
+            if scale * step >= at_least {
+                return scale * step;
+            }
+        }
+        scale *= 10;
+    }
+}
+
+/// Buckets of a round width covering every total in `sorted`.
+fn histogram(sorted: &[u64]) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    let width = round_width((max - min) / BUCKETS + 1);
+    (min / width..=max / width)
+        .map(|i| {
+            let from = i * width;
+            let to = from.saturating_add(width - 1);
+            let elves = sorted.iter().filter(|&&t| from <= t && t <= to).count();
+            Bucket { from, to, elves }
+        })
+        .collect()
+}
+
+impl Stats {
+    /// The statistics over `elves`, naming the `top` carrying the most, or
+    /// nothing if there are no elves.
+    pub fn new(elves: &Elves, top: usize) -> Option<Self> {
+        let mut totals: Vec<u64> = elves.elves().iter().map(|elf| elf.total()).collect();
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort_unstable();
+
+        let n = totals.len();
+        let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / n as f64;
+        let median = match n % 2 {
+            0 => (totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0,
+            _ => totals[n / 2] as f64,
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * n).div_ceil(100).max(1);
+                (p, totals[rank - 1])
+            })
+            .collect();
+
+        let mut largest_snack: Option<Snack> = None;
+        for elf in elves.elves() {
+            for (i, &calories) in elf.snacks().iter().enumerate() {
+                if largest_snack.is_none_or(|s| calories > s.calories) {
+                    largest_snack = Some(Snack {
+                        elf: elf.index,
+                        snack: i,
+                        calories,
+                    });
+                }
+            }
+        }
+
+        Some(Stats {
+            elves: n,
+            snacks: elves.elves().iter().map(|elf| elf.snacks().len()).sum(),
+            mean,
+            median,
+            percentiles,
+            largest_snack,
+            histogram: histogram(&totals),
+            top: elves
+                .top_n(top)
+                .iter()
+                .map(|elf| Ranked {
+                    index: elf.index,
+                    total: elf.total(),
+                })
+                .collect(),
+        })
+    }
+
+    /// The same report as one JSON object.
+    pub fn json(&self) -> String {
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(p, total)| format!(r#""p{p}":{total}"#))
+            .collect();
+        let largest = match self.largest_snack {
+            Some(s) => format!(
+                r#"{{"elf":{},"snack":{},"calories":{}}}"#,
+                s.elf + 1,
+                s.snack + 1,
+                s.calories
+            ),
+            None => "null".to_string(),
+        };
+        let histogram: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|b| format!(r#"{{"from":{},"to":{},"elves":{}}}"#, b.from, b.to, b.elves))
+            .collect();
+        let top: Vec<String> = self
+            .top
+            .iter()
+            .enumerate()
+            .map(|(rank, elf)| {
+                format!(
+                    r#"{{"rank":{},"elf":{},"total":{}}}"#,
+                    rank + 1,
+                    elf.index + 1,
+                    elf.total
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"elves":{},"snacks":{},"mean":{:.1},"median":{:.1},"percentiles":{{{}}},"largest_snack":{largest},"histogram":[{}],"top":[{}]}}"#,
+            self.elves,
+            self.snacks,
+            self.mean,
+            self.median,
+            percentiles.join(","),
+            histogram.join(","),
+            top.join(",")
+        )
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "elves          {}", self.elves)?;
+        writeln!(f, "snacks         {}", self.snacks)?;
+        writeln!(f, "mean total     {:.1}", self.mean)?;
+        writeln!(f, "median total   {:.1}", self.median)?;
+        for (p, total) in self.percentiles.iter() {
+            writeln!(f, "{:<15}{total}", format!("p{p}"))?;
+        }
+        if let Some(s) = self.largest_snack {
+            writeln!(
+                f,
+                "largest snack  {} (elf {}, snack {})",
+                s.calories,
+                s.elf + 1,
+                s.snack + 1
+            )?;
+        }
+
+        writeln!(f, "\ntotals")?;
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let digits = self.histogram.last().map_or(1, |b| b.to.to_string().len());
+        for b in self.histogram.iter() {
+            let bar = match b.elves {
+                0 => 0,
+                n => (n * BAR / most).max(1),
+            };
+            writeln!(
+                f,
+                "{:>digits$} - {:>digits$}  {:<BAR$} {}",
+                b.from,
+                b.to,
+                "#".repeat(bar),
+                b.elves
+            )?;
+        }
+
+        write!(f, "\ntop")?;
+        for (rank, elf) in self.top.iter().enumerate() {
+            write!(
+                f,
+                "\n{:>3}. elf {:<6} {}",
+                rank + 1,
+                elf.index + 1,
+                elf.total
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{assert_snapshot, Solution};
+
+    use crate::{
+        stats::{histogram, round_width, Bucket, Snack, Stats},
+        Elves,
+    };
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    #[test]
+    fn summarizes() {
+        let stats = Stats::new(&Elves::parse(EXAMPLE).unwrap(), 3).unwrap();
+        assert_eq!((stats.elves, stats.snacks), (5, 10));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert_eq!(
+            stats.percentiles,
+            [
+                (10, 4000),
+                (25, 6000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!(
+            stats.largest_snack,
+            Some(Snack {
+                elf: 4,
+                snack: 0,
+                calories: 10000
+            })
+        );
+        let top: Vec<usize> = stats.top.iter().map(|r| r.index).collect();
+        assert_eq!(top, [3, 2, 4]);
+
+        let even = Stats::new(&Elves::parse("1\n\n4").unwrap(), 3).unwrap();
+        assert_eq!(even.median, 2.5);
+        assert_eq!(Stats::new(&Elves::parse("").unwrap(), 3), None);
+    }
+
+    #[test]
+    fn buckets() {
+        assert_eq!(round_width(1), 1);
+        assert_eq!(round_width(3), 5);
+        assert_eq!(round_width(2001), 5000);
+        assert_eq!(
+            histogram(&[7, 7]),
+            [Bucket {
+                from: 7,
+                to: 7,
+                elves: 2
+            }]
+        );
+        let counts: Vec<usize> = histogram(&[4000, 6000, 10000, 11000, 24000])
+            .iter()
+            .map(|b| b.elves)
+            .collect();
+        assert_eq!(counts, [1, 1, 2, 0, 1]);
+        assert_eq!(histogram(&[u64::MAX]).last().unwrap().to, u64::MAX);
+    }
+
+    #[test]
+    fn renders() {
+        let stats = Stats::new(&Elves::parse(EXAMPLE).unwrap(), 3).unwrap();
+        assert_snapshot!("example_stats", stats);
+        assert_eq!(
+            stats.json(),
+            r#"{"elves":5,"snacks":10,"mean":11000.0,"median":10000.0,"percentiles":{"p10":4000,"p25":6000,"p75":11000,"p90":24000,"p99":24000},"largest_snack":{"elf":5,"snack":1,"calories":10000},"histogram":[{"from":0,"to":4999,"elves":1},{"from":5000,"to":9999,"elves":1},{"from":10000,"to":14999,"elves":2},{"from":15000,"to":19999,"elves":0},{"from":20000,"to":24999,"elves":1}],"top":[{"rank":1,"elf":4,"total":24000},{"rank":2,"elf":3,"total":11000},{"rank":3,"elf":5,"total":10000}]}"#
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Top elves from a calorie log of any size, read a line at a time.
+
+use std::{cmp::Reverse, collections::BinaryHeap, error::Error, io::BufRead};
+
+use advent::ParseError;
+
+use crate::add_snack;
+
+/// An elf's place in the input and the calories it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked {
+    pub index: usize,
+    pub total: u64,
+}
+
+/// The `k` largest totals seen so far, kept in a min-heap so each new elf
+/// costs `O(log k)` and memory stays at `k` entries.
+///
+/// The heap grows as elves are pushed rather than being sized from `k`, so a
+/// `k` far beyond the number of elves costs nothing extra.
+pub struct TopK {
+    k: usize,
+    // The smallest total is on top, and of equal totals the elf listed last,
+    // so ties are kept for the earlier elf.
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The kept elves, most calories first.
+    pub fn into_sorted(self) -> Vec<Ranked> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| Ranked { index, total })
+            .collect()
+    }
+}
+
+/// Walks a log read from `reader` a line at a time, passing each elf's index
+/// and total to `elf` once its snacks end.
+///
+/// A snack that does not parse, or would overflow its elf's total, is moved
+/// to where it sits in the log and passed to `error`, and left out of the
+/// total. Returning it from `error` stops the walk there.
+pub fn read_log(
+    mut reader: impl BufRead,
+    mut elf: impl FnMut(usize, u64),
+    mut error: impl FnMut(ParseError) -> Result<(), ParseError>,
+) -> Result<(), Box<dyn Error>> {
+    let mut line = String::new();
+    let mut number = 0;
+    let mut index = 0;
+    // The total of the elf being read, if it has had any snacks yet.
+    let mut current: Option<u64> = None;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+
+        let text = line.trim();
+        if text.is_empty() {
+            if let Some(total) = current.take() {
+                elf(index, total);
+                index += 1;
+            }
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let total = current.get_or_insert(0);
+        if let Err(e) = add_snack(total, text, index) {
+            error(e.shift(indent).on_line(number))?;
+        }
+    }
+    if let Some(total) = current {
+        elf(index, total);
+    }
+    Ok(())
+}
+
+/// The `k` elves carrying the most in a log read from `reader`, most first.
+///
+/// Only the current line and `k` totals are held, whatever the log's size.
+pub fn top_k(reader: impl BufRead, k: usize) -> Result<Vec<Ranked>, Box<dyn Error>> {
+    let mut top = TopK::new(k);
+    read_log(reader, |index, total| top.push(index, total), Err)?;
+    Ok(top.into_sorted())
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::Rng;
+
+    use crate::{
+        parse_elves, reference,
+        stream::{top_k, Ranked, TopK},
+        Elves,
+    };
+
+    #[test]
+    fn keeps_the_largest() {
+        let mut top = TopK::new(2);
+        for (index, total) in [5, 9, 1, 9, 7].into_iter().enumerate() {
+            top.push(index, total);
+        }
+        assert_eq!(
+            top.into_sorted(),
+            [Ranked { index: 1, total: 9 }, Ranked { index: 3, total: 9 }]
+        );
+        assert!(TopK::new(0).into_sorted().is_empty());
+
+        let mut top = TopK::new(usize::MAX);
+        top.push(0, 4);
+        assert_eq!(top.into_sorted(), [Ranked { index: 0, total: 4 }]);
+    }
+
+    #[test]
+    fn streams() {
+        let log = "1000\r\n2000\r\n\r\n4000\n\n\n  5000\n6000\n";
+        let ranked = top_k(log.as_bytes(), 5).unwrap();
+        let totals: Vec<(usize, u64)> = ranked.iter().map(|r| (r.index, r.total)).collect();
+        assert_eq!(totals, [(2, 11000), (1, 4000), (0, 3000)]);
+
+        let error = top_k("1\n\n  2x\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: expected a calorie count, found `2x`"
+        );
+
+        let log = format!("1\n\n{}\n  1\n", u64::MAX);
+        let error = top_k(log.as_bytes(), 3).unwrap_err();
+        assert!(
+            error.to_string().starts_with(
+                "line 4, column 3: expected a calorie count that keeps elf 2's total within"
+            ),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn matches_top_n() {
+        for seed in 0..50 {
+            let input = reference::generate(&mut Rng::new(seed));
+            let elves = Elves::new(parse_elves(&input).unwrap());
+            for k in [1, 3, 10] {
+                let expected: Vec<(usize, u64)> = elves
+                    .top_n(k)
+                    .iter()
+                    .map(|elf| (elf.index, elf.total()))
+                    .collect();
+                let streamed: Vec<(usize, u64)> = top_k(input.as_bytes(), k)
+                    .unwrap()
+                    .iter()
+                    .map(|r| (r.index, r.total))
+                    .collect();
+                assert_eq!(streamed, expected, "seed {seed}, k {k}");
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use advent::{bench::Bencher, input::normalize};
+use rps::{Match, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("Match::points", || {
+        Match::try_from(input.as_str()).unwrap().points()
+    });
+    bencher.bench("Match::shape_points", || {
+        Match::try_from(input.as_str()).unwrap().shape_points()
+    });
+}
@@ -0,0 +1,303 @@
+use advent::{
+    parse,
+    repl::{self, Explore, Session},
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RPS {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl RPS {
+    fn points(&self) -> u32 {
+        match self {
+            RPS::Rock => 1,
+            RPS::Paper => 2,
+            RPS::Scissors => 3,
+        }
+    }
+}
+
+impl TryFrom<&str> for RPS {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "A" => Ok(RPS::Rock),
+            "B" => Ok(RPS::Paper),
+            "C" => Ok(RPS::Scissors),
+            _ => Err(ParseError::new(value, "their shape `A`, `B` or `C`")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Strategy {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl Strategy {
+    fn points(&self) -> u32 {
+        match self {
+            Strategy::Win => 6,
+            Strategy::Lose => 0,
+            Strategy::Draw => 3,
+        }
+    }
+
+    fn choice(&self, them: &RPS) -> RPS {
+        match (self, them) {
+            (Strategy::Win, RPS::Rock) => RPS::Paper,
+            (Strategy::Win, RPS::Paper) => RPS::Scissors,
+            (Strategy::Win, RPS::Scissors) => RPS::Rock,
+            (Strategy::Lose, RPS::Rock) => RPS::Scissors,
+            (Strategy::Lose, RPS::Paper) => RPS::Rock,
+            (Strategy::Lose, RPS::Scissors) => RPS::Paper,
+            (Strategy::Draw, RPS::Rock) => RPS::Rock,
+            (Strategy::Draw, RPS::Paper) => RPS::Paper,
+            (Strategy::Draw, RPS::Scissors) => RPS::Scissors,
+        }
+    }
+
+    /// The column letter read as a shape instead: X, Y, Z for Rock, Paper, Scissors.
+    fn as_shape(&self) -> RPS {
+        match self {
+            Strategy::Lose => RPS::Rock,
+            Strategy::Draw => RPS::Paper,
+            Strategy::Win => RPS::Scissors,
+        }
+    }
+
+    fn outcome(us: &RPS, them: &RPS) -> Strategy {
+        [Strategy::Win, Strategy::Lose, Strategy::Draw]
+            .into_iter()
+            .find(|s| s.choice(them) == *us)
+            .unwrap()
+    }
+}
+
+impl TryFrom<&str> for Strategy {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "X" => Ok(Strategy::Lose),
+            "Y" => Ok(Strategy::Draw),
+            "Z" => Ok(Strategy::Win),
+            _ => Err(ParseError::new(value, "our column `X`, `Y` or `Z`")),
+        }
+    }
+}
+
+pub struct Round {
+    pub them: RPS,
+    pub us: RPS,
+    pub strategy: Strategy,
+}
+
+impl Round {
+    pub fn points(&self) -> u32 {
+        self.strategy.points() + self.us.points()
+    }
+
+    /// Reads the second column as the shape we play, rather than the outcome.
+    pub fn parse_shapes(value: &str) -> Result<Self, ParseError> {
+        Round::try_from(value).map(|r| r.as_shapes())
+    }
+
+    fn as_shapes(&self) -> Round {
+        let them = self.them.clone();
+        let us = self.strategy.as_shape();
+        let strategy = Strategy::outcome(&us, &them);
+        Round { us, them, strategy }
+    }
+}
+
+impl TryFrom<&str> for Round {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (them, strategy) = parse::both(
+            value,
+            " ",
+            "a round like `A Y`",
+            RPS::try_from,
+            Strategy::try_from,
+        )?;
+        let us = strategy.choice(&them);
+        Ok(Round { us, them, strategy })
+    }
+}
+
+pub struct Match {
+    rounds: Vec<Round>,
+}
+
+impl TryFrom<&str> for Match {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let rounds = parse::lines(value, |line| Round::try_from(line))?;
+        Ok(Match { rounds })
+    }
+}
+
+impl Match {
+    pub fn points(&self) -> u32 {
+        self.rounds.iter().map(|r| r.points()).sum()
+    }
+
+    pub fn shape_points(&self) -> u32 {
+        self.rounds.iter().map(|r| r.as_shapes().points()).sum()
+    }
+}
+
+impl Solution for Match {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input.try_into()
+    }
+
+    fn part_one(&self) -> u32 {
+        self.shape_points()
+    }
+
+    fn part_two(&self) -> u32 {
+        self.points()
+    }
+}
+
+/// Looks at single rounds under both readings of the second column.
+struct Referee<'a>(&'a Match);
+
+impl Referee<'_> {
+    fn describe(round: &Round) -> String {
+        format!(
+            "{:?} against {:?}, {:?}: {} points",
+            round.us,
+            round.them,
+            round.strategy,
+            round.points()
+        )
+    }
+}
+
+impl Session for Referee<'_> {
+    fn help(&self) -> &'static str {
+        "round N           what round N scores, counting from 1
+rounds            how many rounds there are
+score             the total under each reading"
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let rounds = &self.0.rounds;
+        match command {
+            "round" => {
+                let n: usize = repl::arg(args, 0, "round", 0)?;
+                let round = n
+                    .checked_sub(1)
+                    .and_then(|i| rounds.get(i))
+                    .ok_or(format!("usage: round N, from 1 to {}", rounds.len()))?;
+                Ok(format!(
+                    "as a shape:    {}\nas an outcome: {}",
+                    Self::describe(&round.as_shapes()),
+                    Self::describe(round)
+                ))
+            }
+            "rounds" => Ok(rounds.len().to_string()),
+            "score" => Ok(format!(
+                "as shapes:   {}\nas outcomes: {}",
+                self.0.shape_points(),
+                self.0.points()
+            )),
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+impl Explore for Match {
+    fn explore(&self) -> Box<dyn Session + '_> {
+        Box::new(Referee(self))
+    }
+}
+
+impl Validate for Match {
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate::lines(input, |line| Round::try_from(line).map(|_| ()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{repl::Explore, validate::Validate};
+
+    use crate::{Match, Round};
+
+    #[test]
+    fn test_round_shapes() {
+        let round = Round::parse_shapes("A Y").unwrap();
+        assert_eq!(round.points(), 8);
+    }
+
+    #[test]
+    fn test_match_shapes() {
+        let r#match: Match = "A Y\nB X\nC Z".try_into().unwrap();
+        assert_eq!(r#match.shape_points(), 15);
+    }
+
+    #[test]
+    fn test_round() {
+        let round: Round = "A Y".try_into().unwrap();
+        assert_eq!(round.points(), 4);
+    }
+
+    #[test]
+    fn test_match() {
+        let r#match: Match = "A Y\nB X\nC Z".try_into().unwrap();
+        assert_eq!(r#match.points(), 12);
+    }
+
+    #[test]
+    fn test_explore() {
+        let r#match: Match = "A Y\nB X\nC Z".try_into().unwrap();
+        let mut referee = r#match.explore();
+        assert_eq!(
+            referee.run("round", &["1"]),
+            Ok("as a shape:    Paper against Rock, Win: 8 points
+as an outcome: Rock against Rock, Draw: 4 points"
+                .to_string())
+        );
+        assert_eq!(
+            referee.run("score", &[]),
+            Ok("as shapes:   15\nas outcomes: 12".to_string())
+        );
+        assert!(referee.run("round", &["0"]).is_err());
+        assert!(referee.run("round", &["4"]).is_err());
+    }
+
+    #[test]
+    fn test_bad_round() {
+        let error = Match::try_from("A Y\nB Q").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "Q");
+    }
+
+    #[test]
+    fn validates_every_round() {
+        let errors = Match::validate("A Y\nD Y\nB X Z\nC Z");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(found, [(2, 1), (3, 3)]);
+    }
+}
@@ -0,0 +1,77 @@
+//! Random strategy guides, scored round by round from the puzzle's rules.
+
+use advent::random::Rng;
+
+/// A strategy guide of up to fifty rounds.
+pub fn generate(rng: &mut Rng) -> String {
+    let rounds: Vec<String> = (0..rng.usize(1..=50))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.pick(&["A", "B", "C"]),
+                rng.pick(&["X", "Y", "Z"])
+            )
+        })
+        .collect();
+    rounds.join("\n")
+}
+
+/// Every round's score, worked out by hand from the puzzle's rules.
+fn score(input: &str, table: [(&str, u32); 9]) -> u32 {
+    input
+        .lines()
+        .map(|line| table.iter().find(|(round, _)| *round == line).unwrap().1)
+        .sum()
+}
+
+/// The second column is the shape we play.
+pub fn part_one(input: &str) -> u32 {
+    score(
+        input,
+        [
+            ("A X", 1 + 3),
+            ("A Y", 2 + 6),
+            ("A Z", 3),
+            ("B X", 1),
+            ("B Y", 2 + 3),
+            ("B Z", 3 + 6),
+            ("C X", 1 + 6),
+            ("C Y", 2),
+            ("C Z", 3 + 3),
+        ],
+    )
+}
+
+/// The second column is whether we lose, draw or win.
+pub fn part_two(input: &str) -> u32 {
+    score(
+        input,
+        [
+            ("A X", 3),
+            ("A Y", 1 + 3),
+            ("A Z", 2 + 6),
+            ("B X", 1),
+            ("B Y", 2 + 3),
+            ("B Z", 3 + 6),
+            ("C X", 2),
+            ("C Y", 3 + 3),
+            ("C Z", 1 + 6),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Match};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Match>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use advent::{bench::Bencher, input::normalize};
+use rucksack::{sum_badges, sum_rucksacks, Rucksack, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("sum_rucksacks", || {
+        sum_rucksacks(Rucksack::make_party(&input).unwrap())
+    });
+    bencher.bench("Rucksack::by_group", || Rucksack::by_group(&input).unwrap());
+    bencher.bench("sum_badges", || {
+        sum_badges(&Rucksack::by_group(&input).unwrap())
+    });
+}
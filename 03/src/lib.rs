@@ -0,0 +1,310 @@
+use advent::{
+    parse, trace,
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+use std::collections::BTreeSet;
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct Item {
+    score: u8,
+}
+
+impl TryFrom<char> for Item {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'a'..='z' => Ok(Item {
+                score: value as u8 - 96,
+            }),
+            'A'..='Z' => Ok(Item {
+                score: value as u8 - 64 + 26,
+            }),
+            _ => Err(ParseError::new(
+                &value.to_string(),
+                "an item `a`-`z` or `A`-`Z`",
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Rucksack {
+    left: BTreeSet<Item>,
+    right: BTreeSet<Item>,
+}
+
+impl Rucksack {
+    pub fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut items = parse::chars(value, Item::try_from)?;
+        let right = items.split_off(items.len() / 2);
+        trace!(
+            "compartments {} {}",
+            &value[..items.len()],
+            &value[items.len()..]
+        );
+        Ok(Rucksack {
+            left: items.into_iter().collect(),
+            right: right.into_iter().collect(),
+        })
+    }
+}
+
+impl TryFrom<&str> for Rucksack {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Rucksack::parse(value)
+    }
+}
+
+impl Rucksack {
+    pub fn priority(&self) -> u32 {
+        let overlap = self.left.intersection(&self.right);
+        overlap.fold(0, |a, i| a + i.score as u32)
+    }
+
+    pub fn make_party(value: &str) -> Result<Vec<Self>, ParseError> {
+        parse::lines(value, Rucksack::parse)
+    }
+
+    pub fn by_group(value: &str) -> Result<Vec<(Rucksack, Rucksack, Rucksack)>, ParseError> {
+        Ok(Rucksack::groups(&Rucksack::make_party(value)?))
+    }
+
+    /// The rucksacks in threes, leaving out any short group at the end.
+    pub fn groups(party: &[Rucksack]) -> Vec<(Rucksack, Rucksack, Rucksack)> {
+        party
+            .chunks_exact(3)
+            .map(|c| (c[0].clone(), c[1].clone(), c[2].clone()))
+            .collect()
+    }
+
+    /// The item all three elves carry, if there is one.
+    pub fn find_badge(elves: &(Rucksack, Rucksack, Rucksack)) -> Option<Item> {
+        let (a, b, c) = elves;
+        let a: BTreeSet<Item> = a.right.union(&a.left).cloned().collect();
+        let b: BTreeSet<Item> = b.right.union(&b.left).cloned().collect();
+        let c: BTreeSet<Item> = c.right.union(&c.left).cloned().collect();
+
+        let s: BTreeSet<Item> = a.intersection(&b).cloned().collect();
+        let s: BTreeSet<Item> = s.intersection(&c).cloned().collect();
+
+        s.into_iter().next()
+    }
+}
+
+pub fn sum_rucksacks(sacks: Vec<Rucksack>) -> u32 {
+    sacks.iter().fold(0, |a, s| a + s.priority())
+}
+
+/// Groups without a badge add nothing; [`Party`] rejects them when parsing.
+pub fn sum_badges(groups: &[(Rucksack, Rucksack, Rucksack)]) -> u32 {
+    groups
+        .iter()
+        .filter_map(Rucksack::find_badge)
+        .fold(0u32, |a, badge| a + badge.score as u32)
+}
+
+const GROUPS: &str = "rucksacks in groups of three";
+const BADGE: &str = "a group of three rucksacks sharing a badge item";
+
+/// The non-blank lines of `input`, each with its index.
+fn rucksack_lines(input: &str) -> Vec<(usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect()
+}
+
+/// An error covering the rucksack on line `i + 1`.
+fn error_at((i, line): (usize, &str), expected: &str) -> ParseError {
+    ParseError::new(line.trim(), expected)
+        .on_line(i + 1)
+        .shift(line.len() - line.trim_start().len())
+}
+
+/// The rucksacks, and the badge of each group of three.
+pub struct Party(Vec<Rucksack>, Vec<Item>);
+
+impl Solution for Party {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    /// Fails on a short last group, or a group with no item in common, so
+    /// that part two always has a badge for every group.
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let party = Rucksack::make_party(input)?;
+        let lines = rucksack_lines(input);
+        let mut badges = Vec::new();
+        for (group, at) in party.chunks(3).zip(lines.chunks(3)) {
+            let [a, b, c] = group else {
+                return Err(error_at(at[at.len() - 1], GROUPS));
+            };
+            let badge = Rucksack::find_badge(&(a.clone(), b.clone(), c.clone()));
+            badges.push(badge.ok_or_else(|| error_at(at[0], BADGE))?);
+        }
+        Ok(Party(party, badges))
+    }
+
+    fn part_one(&self) -> u32 {
+        self.0.iter().fold(0, |a, s| a + s.priority())
+    }
+
+    fn part_two(&self) -> u32 {
+        self.1.iter().fold(0, |a, badge| a + badge.score as u32)
+    }
+}
+
+impl Validate for Party {
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut errors = validate::lines(input, |line| {
+            parse::chars(line, Item::try_from)?;
+            if !line.len().is_multiple_of(2) {
+                return Err(ParseError::new(
+                    line,
+                    "an even number of items to split between the compartments",
+                ));
+            }
+            Ok(())
+        });
+
+        // Groups with a rucksack that does not parse have already been
+        // reported, and are not checked for a badge.
+        for at in rucksack_lines(input).chunks(3) {
+            let [a, b, c] = at else {
+                errors.push(error_at(at[at.len() - 1], GROUPS));
+                continue;
+            };
+            let parse = |(_, line): &(usize, &str)| Rucksack::parse(line.trim());
+            if let (Ok(x), Ok(y), Ok(z)) = (parse(a), parse(b), parse(c)) {
+                if Rucksack::find_badge(&(x, y, z)).is_none() {
+                    errors.push(error_at(*a, BADGE));
+                }
+            }
+        }
+        errors.sort_by_key(|e| (e.line, e.column));
+        errors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{validate::Validate, Solution};
+
+    use crate::{sum_rucksacks, Party, Rucksack};
+
+    fn assert_priority(value: &str, priority: u32) {
+        let rucksack: Rucksack = value.try_into().unwrap();
+        assert_eq!(rucksack.priority(), priority);
+    }
+
+    #[test]
+    fn makes_rucksack() {
+        assert_priority("vJrwpWtwJgWrhcsFMMfFFhFp", 16);
+        assert_priority("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", 38);
+        assert_priority("PmmdzqPrVvPwwTWBwg", 42);
+        assert_priority("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn", 22);
+        assert_priority("ttgJtRGJQctTZtZT", 20);
+        assert_priority("CrZsJsPPZsGzwwsLwLmpwMDw", 19);
+    }
+
+    #[test]
+    fn make_party() {
+        let rucksacks = Rucksack::make_party(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+    jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+    PmmdzqPrVvPwwTWBwg
+    wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+    ttgJtRGJQctTZtZT
+    CrZsJsPPZsGzwwsLwLmpwMDw",
+        )
+        .unwrap();
+
+        assert_eq!(rucksacks.len(), 6);
+
+        assert_eq!(sum_rucksacks(rucksacks), 157);
+    }
+
+    #[test]
+    fn find_badge() {
+        let groups = Rucksack::by_group(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg",
+        )
+        .unwrap();
+
+        let badge = Rucksack::find_badge(&groups[0]).unwrap();
+        assert_eq!(badge.score, 18);
+    }
+
+    #[test]
+    fn find_badges() {
+        let groups = Rucksack::by_group(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+        )
+        .unwrap();
+
+        let sum = groups.iter().fold(0u32, |a, g| {
+            a + Rucksack::find_badge(g).unwrap().score as u32
+        });
+
+        assert_eq!(sum, 70);
+    }
+
+    #[test]
+    fn bad_item() {
+        let error = Rucksack::make_party("vJrwpWtwJgWrhcsFMMfFFhFp\nttgJtRG1QctTZtZT")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.text, "1");
+    }
+
+    #[test]
+    fn validates_rucksacks() {
+        let errors = Party::validate("abab\nabc\nab1d\nabcd");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.text.as_str())).collect();
+        assert_eq!(found, [(2, "abc"), (3, "1"), (4, "abcd")]);
+        assert_eq!(errors[2].expected, "rucksacks in groups of three");
+
+        let errors = Party::validate("abab\ncdcd\nefef\nabab\nx1\naxax");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.text.as_str())).collect();
+        assert_eq!(found, [(1, "abab"), (5, "1")]);
+        assert_eq!(
+            errors[0].expected,
+            "a group of three rucksacks sharing a badge item"
+        );
+    }
+
+    #[test]
+    fn rejects_groups() {
+        let error = Party::parse("abab\nabab\nabab\n\n  abcd\nabab")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 6, column 1: expected rucksacks in groups of three, found `abab`"
+        );
+
+        let error = Party::parse("abab\nabab\nabab\n  abcd\ncdcd\nefef")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 3: expected a group of three rucksacks sharing a badge item, found `abcd`"
+        );
+    }
+}
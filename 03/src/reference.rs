@@ -0,0 +1,83 @@
+//! Random rucksacks built around known shared items, found again by trying
+//! every item type.
+
+use advent::random::Rng;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Groups of three rucksacks, each with exactly one item in both compartments
+/// and exactly one badge shared by the group.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut sacks = Vec::new();
+    for _ in 0..rng.usize(1..=10) {
+        let mut items: Vec<char> = ITEMS.chars().collect();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        // Each elf draws from its own items, so only the badge is common to all three.
+        for own in items[1..].chunks(17) {
+            let (shared, left_only, right_only) = (own[0], &own[1..9], &own[9..]);
+            let mut left = vec![shared];
+            let mut right = vec![shared];
+            if rng.chance(0.5) {
+                left.push(badge);
+            } else {
+                right.push(badge);
+            }
+            let size = rng.usize(2..=10);
+            while left.len() < size {
+                left.push(*rng.pick(left_only));
+            }
+            while right.len() < size {
+                right.push(*rng.pick(right_only));
+            }
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            sacks.push(left.into_iter().chain(right).collect::<String>());
+        }
+    }
+    sacks.join("\n")
+}
+
+fn priority(item: char) -> u32 {
+    ITEMS.find(item).unwrap() as u32 + 1
+}
+
+pub fn part_one(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|sack| {
+            let (left, right) = sack.split_at(sack.len() / 2);
+            priority(left.chars().find(|c| right.contains(*c)).unwrap())
+        })
+        .sum()
+}
+
+pub fn part_two(input: &str) -> u32 {
+    let sacks: Vec<&str> = input.lines().collect();
+    sacks
+        .chunks(3)
+        .map(|group| {
+            let badge = ITEMS
+                .chars()
+                .find(|c| group.iter().all(|sack| sack.contains(*c)))
+                .unwrap();
+            priority(badge)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Party};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Party>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use advent::{bench::Bencher, input::normalize};
+use cleanup::{count_covering, count_overlapping, parse_assignments, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("parse_assignments", || parse_assignments(&input).unwrap());
+
+    let pairs = parse_assignments(&input).unwrap();
+    bencher.bench("count_covering", || count_covering(&pairs));
+    bencher.bench("count_overlapping", || count_overlapping(&pairs));
+}
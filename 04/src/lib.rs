@@ -0,0 +1,166 @@
+use advent::{
+    parse,
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+use std::ops::RangeInclusive;
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+pub struct Range(RangeInclusive<u32>);
+
+impl Range {
+    pub fn contains(&self, other: &Range) -> bool {
+        let start = self.0.contains(other.0.start());
+        let end = self.0.contains(other.0.end());
+        start && end
+    }
+
+    pub fn overlap(&self, other: &Range) -> bool {
+        self.0.start() <= other.0.end() && other.0.start() <= self.0.end()
+    }
+
+    pub fn cover(&self, other: &Range) -> bool {
+        let a = self.contains(other);
+        let b = other.contains(self);
+
+        a || b
+    }
+}
+
+fn parse_range(value: &str) -> Result<Range, ParseError> {
+    let (a, b) = parse::int_pair(value, "-", "a range like `2-4`")?;
+    Ok(Range(a..=b))
+}
+
+pub fn parse_range_pairs(value: &str) -> Result<(Range, Range), ParseError> {
+    parse::both(
+        value,
+        ",",
+        "a pair of ranges like `2-4,6-8`",
+        parse_range,
+        parse_range,
+    )
+}
+
+pub fn parse_assignments(value: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    parse::lines(value, parse_range_pairs)
+}
+
+pub fn count_covering(pairs: &[(Range, Range)]) -> usize {
+    pairs.iter().filter(|(a, b)| a.cover(b)).count()
+}
+
+pub fn count_overlapping(pairs: &[(Range, Range)]) -> usize {
+    pairs.iter().filter(|(a, b)| a.overlap(b)).count()
+}
+
+pub struct Assignments(Vec<(Range, Range)>);
+
+impl Solution for Assignments {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Assignments(parse_assignments(input)?))
+    }
+
+    fn part_one(&self) -> usize {
+        count_covering(&self.0)
+    }
+
+    fn part_two(&self) -> usize {
+        count_overlapping(&self.0)
+    }
+}
+
+fn check_range(value: &str) -> Result<(), ParseError> {
+    let (a, b): (u32, u32) = parse::int_pair(value, "-", "a range like `2-4`")?;
+    if a > b {
+        return Err(ParseError::new(value, "a range that starts before it ends"));
+    }
+    Ok(())
+}
+
+impl Validate for Assignments {
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate::lines(input, |line| {
+            parse::both(
+                line,
+                ",",
+                "a pair of ranges like `2-4,6-8`",
+                check_range,
+                check_range,
+            )
+            .map(|_| ())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::validate::Validate;
+
+    use crate::{count_overlapping, parse_assignments, parse_range_pairs, Assignments};
+
+    fn parse_cover(value: &str, should_cover: bool) {
+        let (a, b) = parse_range_pairs(value).unwrap();
+        assert_eq!(a.cover(&b), should_cover);
+    }
+
+    fn parse_overlap(value: &str, should_overlap: bool) {
+        let (a, b) = parse_range_pairs(value).unwrap();
+        assert_eq!(a.overlap(&b), should_overlap);
+    }
+
+    #[test]
+    fn ranges() {
+        parse_cover("2-4,6-8", false);
+        parse_cover("2-3,4-5", false);
+        parse_cover("5-7,7-9", false);
+        parse_cover("2-8,3-7", true);
+        parse_cover("6-6,4-6", true);
+        parse_cover("2-6,4-8", false);
+    }
+
+    #[test]
+    fn contains_one_way() {
+        let (a, b) = parse_range_pairs("2-8,3-7").unwrap();
+        assert!(a.contains(&b));
+        assert!(!b.contains(&a));
+    }
+
+    #[test]
+    fn overlaps() {
+        parse_overlap("2-4,6-8", false);
+        parse_overlap("2-3,4-5", false);
+        parse_overlap("5-7,7-9", true);
+        parse_overlap("2-8,3-7", true);
+        parse_overlap("6-6,4-6", true);
+        parse_overlap("2-6,4-8", true);
+
+        let pairs =
+            parse_assignments("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
+        assert_eq!(count_overlapping(&pairs), 4);
+    }
+
+    #[test]
+    fn bad_range() {
+        let error = parse_assignments("2-4,6-8\n2-3,4x5").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.text, "4x5");
+
+        let error = parse_assignments("2-4,6-8\n2-3,4-y").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.text, "y");
+    }
+
+    #[test]
+    fn validates_ranges() {
+        let errors = Assignments::validate("2-4,6-8\n5-3,1-1\n1-2,9-x\n3-3,8-7");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(found, [(2, 1), (3, 7), (4, 5)]);
+    }
+}
@@ -0,0 +1,60 @@
+//! Random section assignments, compared by listing every section.
+
+use advent::random::Rng;
+
+/// Pairs of section ranges within `1..=99`.
+pub fn generate(rng: &mut Rng) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.usize(1..=99);
+        format!("{start}-{}", rng.usize(start..=99))
+    };
+    let pairs: Vec<String> = (0..rng.usize(1..=50))
+        .map(|_| format!("{},{}", range(rng), range(rng)))
+        .collect();
+    pairs.join("\n")
+}
+
+/// Every section each elf in the pair is assigned.
+fn sections(input: &str) -> Vec<(Vec<u32>, Vec<u32>)> {
+    let range = |r: &str| {
+        let (a, b) = r.split_once('-').unwrap();
+        (a.parse().unwrap()..=b.parse().unwrap()).collect()
+    };
+    input
+        .lines()
+        .map(|line| {
+            let (a, b) = line.split_once(',').unwrap();
+            (range(a), range(b))
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> usize {
+    sections(input)
+        .iter()
+        .filter(|(a, b)| a.iter().all(|s| b.contains(s)) || b.iter().all(|s| a.contains(s)))
+        .count()
+}
+
+pub fn part_two(input: &str) -> usize {
+    sections(input)
+        .iter()
+        .filter(|(a, b)| a.iter().any(|s| b.contains(s)))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Assignments};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Assignments>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use advent::{bench::Bencher, input::normalize};
+use start_of_packet::{start_of_packet, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("start_of_packet/4", || start_of_packet(input.as_bytes(), 4));
+    bencher.bench("start_of_packet/14", || {
+        start_of_packet(input.as_bytes(), 14)
+    });
+}
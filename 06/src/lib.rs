@@ -0,0 +1,78 @@
+use advent::{parse, ParseError, Solution};
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+// fn all_unique(a: u8, b: u8, c: u8, d: u8) -> bool {
+//     !(a == b || a == c || a == d || b == c || b == d || c == d)
+// }
+
+fn all_unique(slice: &[u8]) -> bool {
+    for i in 0..(slice.len() - 1) {
+        for j in (i + 1)..slice.len() {
+            if slice[i] == slice[j] {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub fn start_of_packet(stream: &[u8], size: usize) -> usize {
+    for i in size..=stream.len() {
+        if all_unique(&stream[(i - size)..i]) {
+            return i;
+        }
+    }
+    0
+}
+
+pub struct Datastream(Vec<u8>);
+
+impl Solution for Datastream {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let stream = parse::chars(input.trim(), |c| match c {
+            'a'..='z' => Ok(c as u8),
+            _ => Err(ParseError::new(&c.to_string(), "a letter `a`-`z`")),
+        })?;
+        Ok(Datastream(stream))
+    }
+
+    fn part_one(&self) -> usize {
+        start_of_packet(&self.0, 4)
+    }
+
+    fn part_two(&self) -> usize {
+        start_of_packet(&self.0, 14)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::start_of_packet;
+
+    #[test]
+    fn part_1() {
+        assert_eq!(start_of_packet(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4), 7);
+        assert_eq!(start_of_packet(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 4), 5);
+        assert_eq!(start_of_packet(b"nppdvjthqldpwncqszvftbrmjlhg", 4), 6);
+        assert_eq!(start_of_packet(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4), 10);
+        assert_eq!(start_of_packet(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4), 11);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(start_of_packet(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), 19);
+        assert_eq!(start_of_packet(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 14), 23);
+        assert_eq!(start_of_packet(b"nppdvjthqldpwncqszvftbrmjlhg", 14), 23);
+        assert_eq!(
+            start_of_packet(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            29
+        );
+        assert_eq!(start_of_packet(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14), 26);
+    }
+}
@@ -0,0 +1,45 @@
+//! Random datastreams, searched for markers by checking every window.
+
+use std::collections::HashSet;
+
+use advent::random::Rng;
+
+/// A stream over a small alphabet, so repeats are common and markers come late or not at all.
+pub fn generate(rng: &mut Rng) -> String {
+    let letters = rng.usize(4..=20);
+    (0..rng.usize(1..=200))
+        .map(|_| (b'a' + rng.usize(0..=letters - 1) as u8) as char)
+        .collect()
+}
+
+/// Characters read when the last `size` were all different, or 0 if they never are.
+fn marker(input: &str, size: usize) -> usize {
+    let chars: Vec<char> = input.chars().collect();
+    (size..=chars.len())
+        .find(|&end| chars[end - size..end].iter().collect::<HashSet<_>>().len() == size)
+        .unwrap_or(0)
+}
+
+pub fn part_one(input: &str) -> usize {
+    marker(input, 4)
+}
+
+pub fn part_two(input: &str) -> usize {
+    marker(input, 14)
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Datastream};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Datastream>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use advent::{bench::Bencher, input::normalize};
+use folder_size::{FS, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("FS::parse", || FS::parse(&input).unwrap());
+    bencher.bench("FS::parse + find_ideal", || {
+        FS::parse(&input).unwrap().find_ideal()
+    });
+
+    let fs = FS::parse(&input).unwrap();
+    bencher.bench("FS::sum_smallest", || fs.sum_smallest());
+}
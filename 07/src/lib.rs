@@ -0,0 +1,456 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use advent::{
+    debug, parse,
+    repl::{self, Explore, Session},
+    trace,
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+pub enum Entry {
+    Dir(Dir),
+    File(File),
+}
+
+impl Entry {
+    pub fn size(&self) -> usize {
+        match self {
+            Entry::Dir(d) => d.size(),
+            Entry::File(f) => f.size(),
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self, Entry::Dir(_))
+    }
+}
+
+pub struct File {
+    size: usize,
+}
+
+impl File {
+    pub fn new(size: usize) -> Self {
+        File { size }
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[derive(Default)]
+pub struct Dir {
+    entries: BTreeMap<String, Entry>,
+}
+
+impl Dir {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add_entry(&mut self, name: String, entry: Entry) {
+        self.entries.entry(name.clone()).or_insert(entry);
+    }
+
+    pub fn find_mut(&mut self, name: &String) -> Option<&mut Entry> {
+        self.entries.get_mut(name)
+    }
+
+    /// The entries in this directory, by name.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &Entry)> {
+        self.entries
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry))
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.iter().fold(0, |a, (_, e)| a + e.size())
+    }
+}
+
+pub struct FS {
+    root: Entry,
+    cd: Vec<String>,
+}
+
+impl Default for FS {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FS {
+    pub fn new() -> Self {
+        FS {
+            root: Entry::Dir(Default::default()),
+            cd: Default::default(),
+        }
+    }
+
+    /// The top-level directory, `/`.
+    pub fn root(&self) -> &Entry {
+        &self.root
+    }
+
+    /// Changes directory, returning false if `path` is not a directory listed here.
+    pub fn cd(&mut self, path: String) -> bool {
+        match path.as_str() {
+            ".." => {
+                self.cd.pop();
+            }
+            "/" => self.cd.clear(),
+            _ => {
+                let listed = self
+                    .cwd()
+                    .and_then(|dir| dir.find_mut(&path))
+                    .is_some_and(|entry| entry.is_dir());
+                if !listed {
+                    return false;
+                }
+                self.cd.push(path);
+            }
+        }
+        true
+    }
+
+    /// The current directory, or `None` if the path runs through something that is not one.
+    fn cwd(&mut self) -> Option<&mut Dir> {
+        let mut dir = match &mut self.root {
+            Entry::Dir(root) => root,
+            Entry::File(_) => return None,
+        };
+        for p in self.cd.iter() {
+            match dir.find_mut(p)? {
+                Entry::Dir(d) => dir = d,
+                Entry::File(_) => return None,
+            }
+        }
+        Some(dir)
+    }
+
+    pub fn add_entry(&mut self, name: String, entry: Entry) {
+        self.cwd()
+            .expect("cd only enters listed directories")
+            .add_entry(name, entry)
+    }
+
+    pub fn parse(history: &str) -> Result<Self, ParseError> {
+        let mut fs = FS::new();
+
+        parse::lines(history, |line| fs.parse_line(line))?;
+
+        Ok(fs)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), ParseError> {
+        if let Some(command) = line.strip_prefix("$ ") {
+            let expected = "a command `cd DIR` or `ls`";
+            match command {
+                "ls" => {}
+                _ => match parse::split(command, " ", expected) {
+                    Ok(("cd", path)) => {
+                        if !self.cd(path.to_string()) {
+                            let error = ParseError::new(path, "a directory listed by `ls`");
+                            return Err(error.shift(5));
+                        }
+                    }
+                    _ => return Err(ParseError::new(command, expected).shift(2)),
+                },
+            }
+        } else {
+            let (size, name) = parse::split(line, " ", "an entry like `dir a` or `584 i`")?;
+            let entry = if size == "dir" {
+                Entry::Dir(Default::default())
+            } else {
+                Entry::File(File::new(parse::int(size, "a file size or `dir`")?))
+            };
+            self.add_entry(name.to_string(), entry);
+        }
+        Ok(())
+    }
+
+    pub fn sum_smallest(&self) -> usize {
+        let mut sum = 0;
+
+        let mut stack: VecDeque<&Entry> = VecDeque::new();
+        stack.push_back(&self.root);
+
+        while !stack.is_empty() {
+            let next = stack.pop_front();
+            if let Some(Entry::Dir(d)) = next {
+                d.entries.values().for_each(|e| stack.push_back(e));
+                let q = d.size();
+                if q <= 100_000 {
+                    sum += q
+                }
+            }
+        }
+
+        sum
+    }
+
+    pub fn find_ideal(&self) -> usize {
+        let unused = 70000000usize.saturating_sub(self.root.size());
+        let missing = 30000000usize.saturating_sub(unused);
+        if missing == 0 {
+            debug!("already {unused} bytes free, nothing to delete");
+            return 0;
+        }
+        let mut best = usize::MAX;
+
+        debug!("looking for {missing} bytes");
+
+        let mut stack: VecDeque<&Entry> = VecDeque::new();
+        stack.push_back(&self.root);
+
+        while !stack.is_empty() {
+            let next = stack.pop_front();
+            if let Some(Entry::Dir(d)) = next {
+                d.entries.values().for_each(|e| stack.push_back(e));
+                let q = d.size();
+                if q >= missing && q < best {
+                    trace!("improving to {q}");
+                    best = q;
+                }
+            }
+        }
+
+        best
+    }
+}
+
+impl Solution for FS {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        FS::parse(input)
+    }
+
+    fn part_one(&self) -> usize {
+        self.sum_smallest()
+    }
+
+    fn part_two(&self) -> usize {
+        self.find_ideal()
+    }
+}
+
+/// Walks the tree with `cd`, `ls`, `du` and `tree`, starting at `/`.
+struct Shell<'a> {
+    fs: &'a FS,
+    cwd: Vec<String>,
+}
+
+impl Shell<'_> {
+    /// Resolves `path` against the working directory.
+    fn resolve(&self, path: Option<&str>) -> Result<(Vec<String>, &Entry), String> {
+        let path = path.unwrap_or(".");
+        let mut names = if path.starts_with('/') {
+            Vec::new()
+        } else {
+            self.cwd.clone()
+        };
+        for part in path.split('/').filter(|p| !p.is_empty() && *p != ".") {
+            if part == ".." {
+                names.pop();
+            } else {
+                names.push(part.to_string());
+            }
+        }
+
+        let mut entry = &self.fs.root;
+        for name in names.iter() {
+            entry = match entry {
+                Entry::Dir(dir) => dir.entries.get(name),
+                Entry::File(_) => None,
+            }
+            .ok_or(format!("no such file or directory: {path}"))?;
+        }
+        Ok((names, entry))
+    }
+
+    fn tree(out: &mut String, name: &str, entry: &Entry, depth: usize, max: usize) {
+        let indent = "  ".repeat(depth);
+        match entry {
+            Entry::File(file) => *out += &format!("{indent}- {name} (file, size={})\n", file.size),
+            Entry::Dir(dir) => {
+                *out += &format!("{indent}- {name} (dir, size={})\n", dir.size());
+                if depth < max {
+                    for (name, entry) in dir.entries.iter() {
+                        Self::tree(out, name, entry, depth + 1, max);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Session for Shell<'_> {
+    fn help(&self) -> &'static str {
+        "cd PATH           change directory
+pwd               print the working directory
+ls [PATH]         list a directory with sizes
+du [PATH]         total size of a file or directory
+tree [PATH] [N]   the tree below PATH, N levels deep"
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "cd" => {
+                let (names, entry) = self.resolve(Some(args.first().copied().unwrap_or("/")))?;
+                if !entry.is_dir() {
+                    return Err(format!("not a directory: {}", args[0]));
+                }
+                self.cwd = names;
+                Ok(String::new())
+            }
+            "pwd" => Ok(format!("/{}", self.cwd.join("/"))),
+            "ls" => match self.resolve(args.first().copied())?.1 {
+                Entry::File(file) => Ok(file.size.to_string()),
+                Entry::Dir(dir) => Ok(dir
+                    .entries
+                    .iter()
+                    .map(|(name, entry)| match entry {
+                        Entry::Dir(d) => format!("{:>10} {name}/", d.size()),
+                        Entry::File(f) => format!("{:>10} {name}", f.size),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")),
+            },
+            "du" => Ok(self.resolve(args.first().copied())?.1.size().to_string()),
+            "tree" => {
+                let (names, entry) = self.resolve(args.first().copied())?;
+                let depth = repl::arg(args, 1, "depth", usize::MAX)?;
+                let name = names.last().map_or("/", String::as_str);
+                let mut out = String::new();
+                Self::tree(&mut out, name, entry, 0, depth);
+                Ok(out.trim_end().to_string())
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+impl Explore for FS {
+    fn explore(&self) -> Box<dyn Session + '_> {
+        Box::new(Shell {
+            fs: self,
+            cwd: Vec::new(),
+        })
+    }
+}
+
+impl Validate for FS {
+    /// A line that fails leaves the tree as it was, so a bad `cd` is reported
+    /// and the listing carries on in the directory before it.
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut fs = FS::new();
+        validate::lines(input, |line| fs.parse_line(line))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{assert_snapshot, repl::Explore, validate::Validate};
+
+    use crate::FS;
+
+    const TEST_DATA: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn parse_input() {
+        FS::parse(TEST_DATA).unwrap();
+    }
+
+    #[test]
+    fn sum_smallest() {
+        let fs = FS::parse(TEST_DATA).unwrap();
+        let sum = fs.sum_smallest();
+        assert_eq!(sum, 95437);
+    }
+
+    #[test]
+    fn ideal() {
+        let fs = FS::parse(TEST_DATA).unwrap();
+        let ideal = fs.find_ideal();
+        assert_eq!(ideal, 24933642);
+
+        let fs = FS::parse("$ cd /\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ ls\n584 i").unwrap();
+        assert_eq!(fs.find_ideal(), 0);
+    }
+
+    #[test]
+    fn explores() {
+        let fs = FS::parse(TEST_DATA).unwrap();
+        let mut shell = fs.explore();
+        assert_eq!(shell.run("du", &[]), Ok("48381165".to_string()));
+        assert_eq!(
+            shell.run("ls", &["/a"]),
+            Ok("       584 e/\n     29116 f\n      2557 g\n     62596 h.lst".to_string())
+        );
+        shell.run("cd", &["a/e"]).unwrap();
+        assert_eq!(shell.run("pwd", &[]), Ok("/a/e".to_string()));
+        assert_eq!(shell.run("du", &["../../d"]), Ok("24933642".to_string()));
+        assert!(shell.run("cd", &["i"]).is_err());
+        assert!(shell.run("ls", &["nope"]).is_err());
+        assert_snapshot!("example_tree", shell.run("tree", &["/"]).unwrap());
+        assert_eq!(
+            shell.run("tree", &["/", "0"]),
+            Ok("- / (dir, size=48381165)".to_string())
+        );
+    }
+
+    #[test]
+    fn bad_history() {
+        let error = FS::parse("$ cd /\n$ ls\n12x b.txt").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "12x");
+
+        let error = FS::parse("$ cd /\n$ rm -rf a").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = FS::parse("$ cd /\n$ cd a").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        let error = FS::parse("$ ls\n584 i\n$ cd i\n$ ls\n1 j").err().unwrap();
+        assert_eq!((error.line, error.text.as_str()), (3, "i"));
+    }
+
+    #[test]
+    fn validates_history() {
+        let errors =
+            FS::validate("$ cd /\n$ ls\ndir a\nlots b.txt\n$ cd c\n$ cd a\n$ pwd\n$ ls\n584 i");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(found, [(4, 1), (5, 6), (7, 3)]);
+        assert!(FS::validate(TEST_DATA).is_empty());
+    }
+}
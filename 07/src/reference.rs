@@ -0,0 +1,138 @@
+//! Random directory trees, sized straight from the transcript without
+//! building a tree.
+
+use std::collections::HashMap;
+
+use advent::random::Rng;
+
+const DISK: usize = 70_000_000;
+const NEEDED: usize = 30_000_000;
+
+#[derive(Default)]
+struct Tree {
+    files: Vec<(String, usize)>,
+    dirs: Vec<(String, Tree)>,
+}
+
+impl Tree {
+    fn generate(rng: &mut Rng, depth: usize) -> Self {
+        let mut tree = Tree::default();
+        for i in 0..rng.usize(0..=3) {
+            let size = if rng.chance(0.7) {
+                rng.usize(1..=50_000)
+            } else {
+                rng.usize(1..=200_000)
+            };
+            tree.files
+                .push((format!("{i}.{}", rng.pick(&["txt", "dat", "log"])), size));
+        }
+        if depth < 3 {
+            let mut names = ["a", "b", "c", "d", "e"];
+            rng.shuffle(&mut names);
+            for name in names.iter().take(rng.usize(0..=3)) {
+                tree.dirs
+                    .push((name.to_string(), Tree::generate(rng, depth + 1)));
+            }
+        }
+        tree
+    }
+
+    fn size(&self) -> usize {
+        let files: usize = self.files.iter().map(|(_, size)| size).sum();
+        files + self.dirs.iter().map(|(_, dir)| dir.size()).sum::<usize>()
+    }
+
+    fn transcript(&self, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_string());
+        lines.extend(self.dirs.iter().map(|(name, _)| format!("dir {name}")));
+        lines.extend(
+            self.files
+                .iter()
+                .map(|(name, size)| format!("{size} {name}")),
+        );
+        for (name, dir) in self.dirs.iter() {
+            lines.push(format!("$ cd {name}"));
+            dir.transcript(lines);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+}
+
+/// A depth-first exploration of a random tree, usually padded so the disk is
+/// between 40M and 69M full and something has to be deleted, but sometimes
+/// left with room to spare.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut root = Tree::generate(rng, 0);
+    let used = if rng.chance(0.8) {
+        rng.usize(DISK - NEEDED + 1..=69_000_000)
+    } else {
+        rng.usize(root.size()..=DISK - NEEDED)
+    };
+    if used > root.size() {
+        root.files.push(("pad.bin".to_string(), used - root.size()));
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    root.transcript(&mut lines);
+    lines.join("\n")
+}
+
+/// The size of every directory, found by adding each file to every directory above it.
+fn sizes(input: &str) -> HashMap<Vec<&str>, usize> {
+    let mut path = Vec::new();
+    let mut sizes = HashMap::from([(vec![], 0)]);
+    for line in input.lines() {
+        match line.split(' ').collect::<Vec<_>>()[..] {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => {
+                path.pop();
+            }
+            ["$", "cd", dir] => {
+                path.push(dir);
+                sizes.entry(path.clone()).or_insert(0);
+            }
+            ["$", "ls"] | ["dir", _] => {}
+            [size, _] => {
+                let size: usize = size.parse().unwrap();
+                for depth in 0..=path.len() {
+                    *sizes.get_mut(&path[..depth]).unwrap() += size;
+                }
+            }
+            _ => panic!("unexpected line {line}"),
+        }
+    }
+    sizes
+}
+
+pub fn part_one(input: &str) -> usize {
+    sizes(input).values().filter(|size| **size <= 100_000).sum()
+}
+
+pub fn part_two(input: &str) -> usize {
+    let sizes = sizes(input);
+    let free = DISK - sizes[&vec![]];
+    if free >= NEEDED {
+        return 0;
+    }
+    *sizes
+        .values()
+        .filter(|size| free + **size >= NEEDED)
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, FS};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<FS>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
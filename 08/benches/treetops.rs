@@ -0,0 +1,13 @@
+use advent::{bench::Bencher, input::normalize};
+use treetops::{Forest, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("Forest::parse", || Forest::parse(&input).unwrap());
+
+    let forest = Forest::parse(&input).unwrap();
+    bencher.bench("Forest::number_visible", || forest.number_visible());
+    bencher.bench("Forest::most_scenic", || forest.most_scenic());
+}
@@ -0,0 +1,361 @@
+use advent::{
+    parse,
+    repl::{self, Explore, Session},
+    trace,
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+use grid::{Direction, Grid, Pos};
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+pub struct Forest(Grid<u8>);
+
+impl Forest {
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.0
+    }
+
+    /// Heights of the trees from `(row, col)` out to the edge, nearest first.
+    fn sightline(&self, row: usize, col: usize, dir: Direction) -> impl Iterator<Item = u8> + '_ {
+        self.0.ray(Pos::new(row, col), dir).map(|(_, h)| *h)
+    }
+
+    pub fn visible(&self, row: usize, col: usize) -> bool {
+        let height = self.0[Pos::new(row, col)];
+        Direction::ORTHOGONAL
+            .iter()
+            .any(|dir| self.sightline(row, col, *dir).all(|h| h < height))
+    }
+
+    /// Trees seen looking `dir` from `(row, col)`, up to and including the first one as tall.
+    fn viewing_distance(&self, row: usize, col: usize, dir: Direction) -> usize {
+        let tallest = self.0[Pos::new(row, col)];
+        let mut count = 0;
+        for height in self.sightline(row, col, dir) {
+            count += 1;
+            if height >= tallest {
+                break;
+            }
+        }
+        count
+    }
+
+    pub fn scenic_up(&self, row: usize, col: usize) -> usize {
+        self.viewing_distance(row, col, Direction::Up)
+    }
+
+    pub fn scenic_down(&self, row: usize, col: usize) -> usize {
+        self.viewing_distance(row, col, Direction::Down)
+    }
+
+    pub fn scenic_right(&self, row: usize, col: usize) -> usize {
+        self.viewing_distance(row, col, Direction::Right)
+    }
+
+    pub fn scenic_left(&self, row: usize, col: usize) -> usize {
+        self.viewing_distance(row, col, Direction::Left)
+    }
+
+    pub fn scenic(&self, row: usize, col: usize) -> usize {
+        self.scenic_up(row, col)
+            * self.scenic_down(row, col)
+            * self.scenic_left(row, col)
+            * self.scenic_right(row, col)
+    }
+
+    pub fn most_scenic(&self) -> usize {
+        let mut c = 0;
+        for Pos { row, col } in self.0.positions() {
+            let scenic = self.scenic(row, col);
+            if scenic > c {
+                trace!("more scenic at {row}, {col} ({scenic})");
+                c = scenic
+            }
+        }
+        c
+    }
+
+    pub fn number_visible(&self) -> usize {
+        self.0
+            .positions()
+            .filter(|pos| self.visible(pos.row, pos.col))
+            .count()
+    }
+}
+
+impl Forest {
+    pub fn parse(grid: &str) -> Result<Self, ParseError> {
+        let trees = parse::lines(grid, |row| {
+            parse::chars(row, |t| {
+                t.to_digit(10)
+                    .map(|h| h as u8)
+                    .ok_or_else(|| ParseError::new(&t.to_string(), "a tree height `0`-`9`"))
+            })
+        })?;
+
+        let trees = Grid::from_rows(trees).map_err(|e| {
+            // `e.row` counts only the rows parsed, which leave out blank lines.
+            let (i, line) = grid
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .nth(e.row)
+                .unwrap_or_default();
+            ParseError::new(line.trim(), format!("a row of {} trees", e.expected))
+                .on_line(i + 1)
+                .shift(line.len() - line.trim_start().len())
+        })?;
+
+        Ok(Forest(trees))
+    }
+}
+
+impl Solution for Forest {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Forest::parse(input)
+    }
+
+    fn part_one(&self) -> usize {
+        self.number_visible()
+    }
+
+    fn part_two(&self) -> usize {
+        self.most_scenic()
+    }
+}
+
+/// Looks up single trees by row and column.
+struct Survey<'a>(&'a Forest);
+
+impl Survey<'_> {
+    fn pos(&self, args: &[&str]) -> Result<Pos, String> {
+        let [row, col] = [0, 1].map(|i| repl::arg(args, i, "row and column", usize::MAX));
+        let pos = Pos::new(row?, col?);
+        if !self.0.heights().contains(pos) {
+            return Err(format!(
+                "usage: ROW COL, within {} rows and {} columns",
+                self.0.heights().height(),
+                self.0.heights().width()
+            ));
+        }
+        Ok(pos)
+    }
+
+    fn visible(&self, Pos { row, col }: Pos) -> String {
+        let height = self.0.heights()[Pos::new(row, col)];
+        let from: Vec<&str> = Direction::ORTHOGONAL
+            .iter()
+            .filter(|dir| self.0.sightline(row, col, **dir).all(|h| h < height))
+            .map(|dir| match dir {
+                Direction::Up => "top",
+                Direction::Down => "bottom",
+                Direction::Left => "left",
+                _ => "right",
+            })
+            .collect();
+        if from.is_empty() {
+            "hidden".to_string()
+        } else {
+            format!("visible from the {}", from.join(", "))
+        }
+    }
+
+    fn scenic(&self, Pos { row, col }: Pos) -> String {
+        let f = self.0;
+        format!(
+            "up {} * left {} * down {} * right {} = {}",
+            f.scenic_up(row, col),
+            f.scenic_left(row, col),
+            f.scenic_down(row, col),
+            f.scenic_right(row, col),
+            f.scenic(row, col)
+        )
+    }
+}
+
+impl Session for Survey<'_> {
+    fn help(&self) -> &'static str {
+        "tree ROW COL [height|visible|scenic]
+                  describe one tree
+best              the most scenic tree
+show              the whole forest"
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "tree" => {
+                let pos = self.pos(args)?;
+                let height = format!("height {}", self.0.heights()[pos]);
+                Ok(match args.get(2) {
+                    None => format!("{height}, {}\n{}", self.visible(pos), self.scenic(pos)),
+                    Some(&"height") => height,
+                    Some(&"visible") => self.visible(pos),
+                    Some(&"scenic") => self.scenic(pos),
+                    Some(other) => return Err(format!("no property `{other}`")),
+                })
+            }
+            "best" => {
+                let best = self
+                    .0
+                    .heights()
+                    .positions()
+                    .max_by_key(|p| self.0.scenic(p.row, p.col))
+                    .ok_or("the forest is empty")?;
+                Ok(format!("{} {}: {}", best.row, best.col, self.scenic(best)))
+            }
+            "show" => Ok(self.0.heights().to_string()),
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+impl Explore for Forest {
+    fn explore(&self) -> Box<dyn Session + '_> {
+        Box::new(Survey(self))
+    }
+}
+
+impl Validate for Forest {
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut width = None;
+        validate::lines(input, |row| {
+            parse::chars(row, |t| match t {
+                '0'..='9' => Ok(()),
+                _ => Err(ParseError::new(&t.to_string(), "a tree height `0`-`9`")),
+            })?;
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(ParseError::new(row, format!("a row of {expected} trees")));
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{repl::Explore, validate::Validate};
+
+    use crate::Forest;
+
+    const TEST_DATA: &str = "30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn part_1() {
+        let forest = Forest::parse(TEST_DATA).unwrap();
+        let visible = forest.number_visible();
+        assert_eq!(visible, 21);
+    }
+
+    #[test]
+    fn part_2() {
+        let forest = Forest::parse(TEST_DATA).unwrap();
+
+        let up = forest.scenic_up(1, 2);
+        assert_eq!(up, 1);
+        let down = forest.scenic_down(1, 2);
+        assert_eq!(down, 2);
+
+        let left = forest.scenic_left(1, 2);
+        assert_eq!(left, 1);
+        let right = forest.scenic_right(1, 2);
+        assert_eq!(right, 2);
+
+        let scenic = forest.scenic(1, 2);
+        assert_eq!(scenic, 4);
+
+        let up = forest.scenic_up(3, 2);
+        assert_eq!(up, 2);
+        let down = forest.scenic_down(3, 2);
+        assert_eq!(down, 1);
+
+        let left = forest.scenic_left(3, 2);
+        assert_eq!(left, 2);
+        let right = forest.scenic_right(3, 2);
+        assert_eq!(right, 2);
+
+        let scenic = forest.scenic(3, 2);
+        assert_eq!(scenic, 8);
+
+        let visible = forest.most_scenic();
+        assert_eq!(visible, 8);
+    }
+
+    #[test]
+    fn explores() {
+        let forest = Forest::parse(TEST_DATA).unwrap();
+        let mut survey = forest.explore();
+        assert_eq!(
+            survey.run("tree", &["3", "2"]),
+            Ok(
+                "height 5, visible from the bottom, left\nup 2 * left 2 * down 1 * right 2 = 8"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            survey.run("tree", &["1", "1", "height"]),
+            Ok("height 5".to_string())
+        );
+        assert_eq!(
+            survey.run("tree", &["2", "2", "visible"]),
+            Ok("hidden".to_string())
+        );
+        assert_eq!(
+            survey.run("best", &[]),
+            Ok("3 2: up 2 * left 2 * down 1 * right 2 = 8".to_string())
+        );
+        assert!(survey.run("tree", &["5", "0"]).is_err());
+        assert!(survey.run("tree", &["1"]).is_err());
+        assert!(survey.run("tree", &["1", "1", "age"]).is_err());
+    }
+
+    #[test]
+    fn bad_tree() {
+        let error = Forest::parse("303\n2a5").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "a");
+    }
+
+    #[test]
+    fn not_square() {
+        let forest = Forest::parse("30373\n25512\n65332").unwrap();
+        assert_eq!(forest.number_visible(), 14);
+        assert_eq!(forest.scenic_right(1, 1), 1);
+        assert_eq!(forest.scenic_left(1, 3), 1);
+
+        let error = Forest::parse("303\n25").err().unwrap();
+        assert_eq!(error.line, 2);
+
+        let error = Forest::parse("303\n\n  25").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.text, "25");
+    }
+
+    #[test]
+    fn validates_rows() {
+        let errors = Forest::validate("303\n2551\n6a3\n33");
+        let found: Vec<_> = errors
+            .iter()
+            .map(|e| (e.line, e.expected.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (2, "a row of 3 trees"),
+                (3, "a tree height `0`-`9`"),
+                (4, "a row of 3 trees")
+            ]
+        );
+        assert!(Forest::validate(TEST_DATA).is_empty());
+    }
+}
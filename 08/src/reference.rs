@@ -0,0 +1,86 @@
+//! Random forests, with each tree's view walked out in all four directions.
+
+use advent::random::Rng;
+
+/// A forest of any shape up to 12 by 12.
+pub fn generate(rng: &mut Rng) -> String {
+    let width = rng.usize(1..=12);
+    let rows: Vec<String> = (0..rng.usize(1..=12))
+        .map(|_| (0..width).map(|_| rng.usize(0..=9).to_string()).collect())
+        .collect();
+    rows.join("\n")
+}
+
+fn heights(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap() as i32).collect())
+        .collect()
+}
+
+/// The trees seen walking from `(row, col)` in steps of `(drow, dcol)`, nearest first.
+fn walk(trees: &[Vec<i32>], row: usize, col: usize, (drow, dcol): (i32, i32)) -> Vec<i32> {
+    let mut seen = Vec::new();
+    let (mut r, mut c) = (row as i32 + drow, col as i32 + dcol);
+    while r >= 0 && c >= 0 && (r as usize) < trees.len() && (c as usize) < trees[0].len() {
+        seen.push(trees[r as usize][c as usize]);
+        r += drow;
+        c += dcol;
+    }
+    seen
+}
+
+const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+pub fn part_one(input: &str) -> usize {
+    let trees = heights(input);
+    let mut visible = 0;
+    for row in 0..trees.len() {
+        for col in 0..trees[row].len() {
+            let height = trees[row][col];
+            if DIRECTIONS
+                .iter()
+                .any(|d| walk(&trees, row, col, *d).iter().all(|h| *h < height))
+            {
+                visible += 1;
+            }
+        }
+    }
+    visible
+}
+
+pub fn part_two(input: &str) -> usize {
+    let trees = heights(input);
+    let mut best = 0;
+    for row in 0..trees.len() {
+        for col in 0..trees[row].len() {
+            let height = trees[row][col];
+            let mut score = 1;
+            for d in DIRECTIONS {
+                let line = walk(&trees, row, col, d);
+                score *= match line.iter().position(|h| *h >= height) {
+                    Some(blocked) => blocked + 1,
+                    None => line.len(),
+                };
+            }
+            best = best.max(score);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Forest};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Forest>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use advent::{bench::Bencher, input::normalize};
+use rope::{track_tail, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("track_tail/2", || track_tail(2, &input).unwrap());
+    bencher.bench("track_tail/10", || track_tail(10, &input).unwrap());
+}
@@ -0,0 +1,523 @@
+use std::{collections::HashSet, fmt::Display};
+
+use advent::{
+    animate::{Animate, Stepper},
+    parse,
+    repl::{self, Explore, Session},
+    trace,
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+use grid::{Direction, Point, SparseGrid};
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+#[derive(Debug, Default)]
+pub struct Rope {
+    knots: Vec<Point>,
+    path: HashSet<Point>,
+    moves: Vec<Move>,
+}
+
+fn snap(vec: &mut [Point], tail: usize) {
+    let head = vec[tail - 1];
+    let tail = &mut vec[tail];
+
+    let drow = head.row - tail.row;
+    let dcol = head.col - tail.col;
+
+    if drow.abs() >= 2 || dcol.abs() >= 2 {
+        tail.row += drow.signum();
+        tail.col += dcol.signum();
+    }
+}
+
+impl Rope {
+    pub fn new(len: usize) -> Self {
+        let mut vec = Vec::with_capacity(len);
+        for _ in 0..len {
+            vec.push(Default::default())
+        }
+        Rope {
+            knots: vec,
+            path: HashSet::new(),
+            moves: Vec::new(),
+        }
+    }
+
+    pub fn tail(&self) -> &Point {
+        self.knots.last().unwrap()
+    }
+
+    fn step(&mut self, dir: Direction) {
+        self.knots[0] = self.knots[0].step(dir);
+    }
+
+    pub fn right(&mut self) {
+        self.step(Direction::Right);
+    }
+
+    pub fn left(&mut self) {
+        self.step(Direction::Left);
+    }
+
+    pub fn up(&mut self) {
+        self.step(Direction::Up);
+    }
+
+    pub fn down(&mut self) {
+        self.step(Direction::Down);
+    }
+
+    pub fn r#move(&mut self, dir: &Dir) {
+        self.step(dir.into());
+
+        for i in 1..self.knots.len() {
+            snap(&mut self.knots, i);
+        }
+    }
+
+    pub fn wiggle(&mut self, moves: &str) -> Result<(), ParseError> {
+        self.follow(&parse_moves(moves)?);
+        Ok(())
+    }
+
+    fn follow(&mut self, moves: &[Move]) {
+        for r#move in moves {
+            for _ in 0..r#move.count {
+                self.r#move(&r#move.dir);
+                self.path.insert(*self.tail());
+                trace!("{:?} visited {}", self.knots, self.path.len());
+            }
+        }
+    }
+
+    /// Where the tail of a fresh rope of `size` knots is after each step of the parsed moves.
+    pub fn trail(&self, size: usize) -> Vec<Point> {
+        let mut rope = Rope::new(size);
+        let mut trail = vec![*rope.tail()];
+        for r#move in self.moves.iter() {
+            for _ in 0..r#move.count {
+                rope.r#move(&r#move.dir);
+                trail.push(*rope.tail());
+            }
+        }
+        trail
+    }
+
+    /// Replays the parsed moves on a fresh rope of `size` knots.
+    pub fn track(&self, size: usize) -> usize {
+        let mut rope = Rope::new(size);
+        rope.follow(&self.moves);
+        rope.path.len()
+    }
+}
+
+impl Rope {
+    /// The visited cells, the start, and the knots drawn over them: `H` for
+    /// the head, then each knot's number, or `*` from the tenth knot on.
+    fn board(&self) -> SparseGrid<char> {
+        let mut board = SparseGrid::new();
+        for p in self.path.iter() {
+            board.insert(*p, '#');
+        }
+        board.insert(Point::default(), 's');
+        for (i, p) in self.knots.iter().enumerate().rev() {
+            let c = if i == 0 {
+                'H'
+            } else {
+                char::from_digit(i as u32, 10).unwrap_or('*')
+            };
+            board.insert(*p, c);
+        }
+        board
+    }
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.board().to_grid('.'))
+    }
+}
+
+/// Plays moves on a fresh rope one step at a time.
+pub struct Wiggle<'a> {
+    rope: Rope,
+    moves: &'a [Move],
+    next: usize,
+    taken: usize,
+}
+
+impl<'a> Wiggle<'a> {
+    /// Rows and columns shown either side of the head.
+    const VIEW: (i32, i32) = (12, 36);
+
+    fn new(size: usize, moves: &'a [Move]) -> Self {
+        Wiggle {
+            rope: Rope::new(size),
+            moves,
+            next: 0,
+            taken: 0,
+        }
+    }
+}
+
+impl Stepper for Wiggle<'_> {
+    fn step(&mut self) -> bool {
+        while self
+            .moves
+            .get(self.next)
+            .is_some_and(|m| self.taken == m.count)
+        {
+            self.next += 1;
+            self.taken = 0;
+        }
+        let Some(r#move) = self.moves.get(self.next) else {
+            return false;
+        };
+        self.rope.r#move(&r#move.dir);
+        self.rope.path.insert(*self.rope.tail());
+        self.taken += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        let head = self.rope.knots[0];
+        let (rows, cols) = Self::VIEW;
+        let view = self.rope.board().view(
+            Point::new(head.row - rows, head.col - cols),
+            Point::new(head.row + rows, head.col + cols),
+            '.',
+        );
+        format!(
+            "{view}\nmove {}/{}, tail visited {}",
+            (self.next + 1).min(self.moves.len()),
+            self.moves.len(),
+            self.rope.path.len()
+        )
+    }
+}
+
+impl Animate for Rope {
+    fn animate(&self, part: u8) -> Box<dyn Stepper + '_> {
+        let size = if part == 1 { 2 } else { 10 };
+        Box::new(Wiggle::new(size, &self.moves))
+    }
+}
+
+/// Steps a rope through the moves on command.
+struct Replay<'a> {
+    moves: &'a [Move],
+    wiggle: Wiggle<'a>,
+    steps: usize,
+}
+
+impl Replay<'_> {
+    /// The longest rope `reset` will make, so a typo cannot ask for more
+    /// knots than there is memory for.
+    const MAX_KNOTS: usize = 1000;
+
+    fn status(&self) -> String {
+        let frame = self.wiggle.frame();
+        let status = frame.lines().last().unwrap_or_default();
+        format!("step {}, {status}", self.steps)
+    }
+}
+
+impl Session for Replay<'_> {
+    fn help(&self) -> &'static str {
+        "step [N]          move the head N steps, 1 by default
+show              draw the rope around its head
+knots             where each knot is
+reset [KNOTS]     start again with 2 to 1000 KNOTS, 2 by default"
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                for _ in 0..repl::arg(args, 0, "steps", 1)? {
+                    if !self.wiggle.step() {
+                        return Ok(format!("{} (done)", self.status()));
+                    }
+                    self.steps += 1;
+                }
+                Ok(self.status())
+            }
+            "show" => Ok(format!("{}\n{}", self.wiggle.frame(), self.status())),
+            "knots" => Ok(self
+                .wiggle
+                .rope
+                .knots
+                .iter()
+                .enumerate()
+                .map(|(i, p)| format!("{i}: {} {}", p.row, p.col))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            "reset" => {
+                let knots = repl::arg(args, 0, "knots", 2)?;
+                if knots < 2 {
+                    return Err("a rope needs at least 2 knots".to_string());
+                }
+                if knots > Replay::MAX_KNOTS {
+                    return Err(format!(
+                        "a rope can have at most {} knots",
+                        Replay::MAX_KNOTS
+                    ));
+                }
+                self.wiggle = Wiggle::new(knots, self.moves);
+                self.steps = 0;
+                Ok(self.status())
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+impl Explore for Rope {
+    fn explore(&self) -> Box<dyn Session + '_> {
+        Box::new(Replay {
+            moves: &self.moves,
+            wiggle: Wiggle::new(2, &self.moves),
+            steps: 0,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum Dir {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl From<&Dir> for Direction {
+    fn from(dir: &Dir) -> Self {
+        match dir {
+            Dir::Left => Direction::Left,
+            Dir::Right => Direction::Right,
+            Dir::Up => Direction::Up,
+            Dir::Down => Direction::Down,
+        }
+    }
+}
+
+impl TryFrom<char> for Dir {
+    type Error = ParseError;
+
+    fn try_from(v: char) -> Result<Self, Self::Error> {
+        match v {
+            'L' => Ok(Dir::Left),
+            'R' => Ok(Dir::Right),
+            'U' => Ok(Dir::Up),
+            'D' => Ok(Dir::Down),
+            _ => Err(ParseError::new(
+                &v.to_string(),
+                "a direction `L`, `R`, `U` or `D`",
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Move {
+    dir: Dir,
+    count: usize,
+}
+
+impl TryFrom<&str> for Move {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (dir, count) = parse::token_int(value, "a move like `R 4`")?;
+        let mut chars = dir.chars();
+        let dir = match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into()?,
+            _ => return Err(ParseError::new(dir, "a direction `L`, `R`, `U` or `D`")),
+        };
+        Ok(Move { dir, count })
+    }
+}
+
+fn parse_moves(moves: &str) -> Result<Vec<Move>, ParseError> {
+    parse::lines(moves, |line| Move::try_from(line))
+}
+
+pub fn track_tail(size: usize, moves: &str) -> Result<usize, ParseError> {
+    let mut rope = Rope::new(size);
+
+    rope.wiggle(moves)?;
+
+    trace!("\n{rope}");
+
+    Ok(rope.path.len())
+}
+
+impl Solution for Rope {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Rope {
+            moves: parse_moves(input)?,
+            ..Default::default()
+        })
+    }
+
+    fn part_one(&self) -> usize {
+        self.track(2)
+    }
+
+    fn part_two(&self) -> usize {
+        self.track(10)
+    }
+}
+
+impl Validate for Rope {
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate::lines(input, |line| Move::try_from(line).map(|_| ()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use advent::{animate::Animate, assert_snapshot, repl::Explore, validate::Validate, Solution};
+    use grid::Point;
+
+    use crate::{snap, track_tail, Rope};
+
+    const TEST_DATA: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const LARGER_DATA: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    pub fn rope_tail() {
+        let spots = track_tail(2, TEST_DATA).unwrap();
+        assert_eq!(spots, 13);
+        let spots = track_tail(10, TEST_DATA).unwrap();
+        assert_eq!(spots, 1);
+    }
+
+    #[test]
+    pub fn diagonal_pull() {
+        // Only ropes longer than two see a knot two rows and two columns
+        // behind; it steps diagonally, not onto the line of the knot ahead.
+        let mut knots = [Point::new(2, 2), Point::new(0, 0)];
+        snap(&mut knots, 1);
+        assert_eq!(knots[1], Point::new(1, 1));
+
+        let mut knots = [Point::new(-2, 1), Point::new(0, 0)];
+        snap(&mut knots, 1);
+        assert_eq!(knots[1], Point::new(-1, 1));
+    }
+
+    #[test]
+    pub fn trails() {
+        let rope = Rope::parse(TEST_DATA).unwrap();
+        let trail = rope.trail(2);
+        assert_eq!(trail.len(), 25);
+        assert_eq!(trail.iter().collect::<HashSet<_>>().len(), 13);
+    }
+
+    #[test]
+    pub fn explores() {
+        let rope = Rope::parse(TEST_DATA).unwrap();
+        let mut replay = rope.explore();
+        assert_eq!(
+            replay.run("step", &["5"]),
+            Ok("step 5, move 2/8, tail visited 4".to_string())
+        );
+        assert_eq!(replay.run("knots", &[]), Ok("0: -1 4\n1: 0 3".to_string()));
+        assert_eq!(
+            replay.run("step", &["100"]),
+            Ok("step 24, move 8/8, tail visited 13 (done)".to_string())
+        );
+        assert_eq!(
+            replay.run("reset", &["10"]),
+            Ok("step 0, move 1/8, tail visited 0".to_string())
+        );
+        assert!(replay.run("reset", &["1"]).is_err());
+        assert!(replay.run("reset", &["100000000000"]).is_err());
+        assert!(replay.run("step", &["x"]).is_err());
+
+        replay.run("reset", &["12"]).unwrap();
+        replay.run("step", &["100"]).unwrap();
+        assert!(replay.run("show", &[]).is_ok());
+    }
+
+    #[test]
+    pub fn long_rope_tail() {
+        let spots = track_tail(10, LARGER_DATA).unwrap();
+        assert_eq!(spots, 36);
+    }
+
+    #[test]
+    pub fn bad_move() {
+        let error = track_tail(2, "R 4\nX 4").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = track_tail(2, "R 4\nU four").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "four");
+    }
+
+    #[test]
+    pub fn draws() {
+        let mut rope = Rope::new(2);
+        rope.wiggle("R 2\nU 2").unwrap();
+        assert_eq!(rope.to_string(), "..H\n..1\ns#.");
+
+        let mut rope = Rope::new(10);
+        rope.wiggle(LARGER_DATA).unwrap();
+        assert_snapshot!("larger_example", rope);
+
+        let mut rope = Rope::new(12);
+        rope.wiggle("R 12").unwrap();
+        assert_eq!(rope.to_string(), "s**987654321H");
+    }
+
+    #[test]
+    pub fn animates() {
+        let rope = Rope::parse(TEST_DATA).unwrap();
+        let mut wiggle = rope.animate(1);
+        let mut steps = 0;
+        while wiggle.step() {
+            steps += 1;
+        }
+        assert_eq!(steps, 24);
+        assert_snapshot!("example_wiggle_end", wiggle.frame());
+
+        let rope = Rope::parse(LARGER_DATA).unwrap();
+        let mut wiggle = rope.animate(2);
+        for _ in 0..30 {
+            wiggle.step();
+        }
+        assert_snapshot!("larger_wiggle_step_30", wiggle.frame());
+    }
+
+    #[test]
+    pub fn validates_moves() {
+        let errors = Rope::validate("R 4\nX 4\nU four\n\nL\nD 1");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(found, [(2, 1), (3, 3), (5, 1)]);
+        assert!(Rope::validate(TEST_DATA).is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+//! Random moves, replayed on a rope whose knots each step to the
+//! neighbouring cell nearest their leader.
+
+use std::collections::HashSet;
+
+use advent::random::Rng;
+
+/// Up to sixty moves of one to nine steps.
+pub fn generate(rng: &mut Rng) -> String {
+    let moves: Vec<String> = (0..rng.usize(1..=60))
+        .map(|_| format!("{} {}", rng.pick(&["L", "R", "U", "D"]), rng.usize(1..=9)))
+        .collect();
+    moves.join("\n")
+}
+
+/// Moves each knot that has fallen behind to whichever neighbouring cell is
+/// nearest its leader.
+fn visited(input: &str, size: usize) -> usize {
+    let mut knots = vec![(0i32, 0i32); size];
+    let mut visited = HashSet::from([(0, 0)]);
+    for line in input.lines() {
+        let (dir, count) = line.split_once(' ').unwrap();
+        let step = match dir {
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            "U" => (0, 1),
+            _ => (0, -1),
+        };
+        for _ in 0..count.parse().unwrap() {
+            knots[0] = (knots[0].0 + step.0, knots[0].1 + step.1);
+            for i in 1..size {
+                let (lead, knot) = (knots[i - 1], knots[i]);
+                let distance = |(x, y): (i32, i32)| (lead.0 - x).pow(2) + (lead.1 - y).pow(2);
+                if distance(knot) > 2 {
+                    knots[i] = (-1..=1)
+                        .flat_map(|dx| (-1..=1).map(move |dy| (knot.0 + dx, knot.1 + dy)))
+                        .min_by_key(|p| distance(*p))
+                        .unwrap();
+                }
+            }
+            visited.insert(knots[size - 1]);
+        }
+    }
+    visited.len()
+}
+
+pub fn part_one(input: &str) -> usize {
+    visited(input, 2)
+}
+
+pub fn part_two(input: &str) -> usize {
+    visited(input, 10)
+}
+
+#[cfg(test)]
+mod test {
+    use advent::random::check_reference;
+
+    use crate::{reference, Rope};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<Rope>(
+            reference::generate,
+            reference::part_one,
+            reference::part_two,
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use advent::{bench::Bencher, input::normalize};
+use crt::{CRT, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("CRT::run", || CRT::default().run(&input).unwrap());
+}
@@ -0,0 +1,350 @@
+use std::fmt::Display;
+
+use advent::{
+    animate::{Animate, Stepper},
+    debug, parse,
+    repl::{self, Explore, Session},
+    validate::{self, Validate},
+    Image, ParseError, Solution,
+};
+use grid::{Grid, Pos};
+
+pub mod reference;
+
+pub const INPUT: &str = include_str!("../data");
+
+pub struct CRT {
+    x: i32,
+    cycle: i32,
+    recordings: Vec<i32>,
+    screen: Grid<char>,
+    program: Vec<Operation>,
+}
+
+impl Default for CRT {
+    fn default() -> Self {
+        let screen = Grid::new(40, 6, ' ');
+
+        Self {
+            x: 1,
+            cycle: 0,
+            recordings: Vec::new(),
+            screen,
+            program: Vec::new(),
+        }
+    }
+}
+
+impl CRT {
+    fn draw(&mut self) {
+        let row = self.cycle as usize / self.screen.width();
+        let col = self.cycle as usize % self.screen.width();
+
+        if (self.x - 1..=self.x + 1).contains(&(col as i32)) {
+            if let Some(pixel) = self.screen.get_mut(Pos::new(row, col)) {
+                *pixel = '#';
+            }
+        }
+    }
+
+    fn cycle(&mut self) {
+        self.draw();
+        self.cycle += 1;
+        if self.cycle == 20 || (self.cycle - 20) % 40 == 0 {
+            let value = self.cycle * self.x;
+            debug!("cycle {} x {} pushing {value}", self.cycle, self.x);
+            self.recordings.push(value);
+        }
+    }
+
+    fn noop(&mut self) {
+        self.cycle();
+    }
+
+    fn addx(&mut self, dx: i32) {
+        self.cycle();
+        self.cycle();
+        self.x += dx;
+    }
+
+    fn op(&mut self, op: &Operation) {
+        match op {
+            Operation::Noop => self.noop(),
+            Operation::Addx(dx) => self.addx(*dx),
+        }
+    }
+
+    pub fn run(&mut self, ops: &str) -> Result<i32, ParseError> {
+        Ok(self.execute(&parse_program(ops)?))
+    }
+
+    fn execute(&mut self, ops: &[Operation]) -> i32 {
+        ops.iter().for_each(|op| self.op(op));
+        self.recordings.iter().sum()
+    }
+
+    /// Runs the parsed program on a fresh CRT.
+    fn replay(&self) -> (CRT, i32) {
+        let mut crt = CRT::default();
+        let sum = crt.execute(&self.program);
+        (crt, sum)
+    }
+}
+
+impl Solution for CRT {
+    type PartOne = i32;
+    type PartTwo = Image;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(CRT {
+            program: parse_program(input)?,
+            ..Default::default()
+        })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.replay().1
+    }
+
+    fn part_two(&self) -> Image {
+        Image(self.replay().0.to_string())
+    }
+}
+
+impl Display for CRT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.screen)
+    }
+}
+
+/// Runs a program on a fresh CRT one cycle at a time.
+pub struct Beam<'a> {
+    crt: CRT,
+    program: &'a [Operation],
+    next: usize,
+    /// Whether the current `addx` has already used its first cycle.
+    halfway: bool,
+}
+
+impl Stepper for Beam<'_> {
+    fn step(&mut self) -> bool {
+        let Some(op) = self.program.get(self.next) else {
+            return false;
+        };
+        self.crt.cycle();
+        match op {
+            Operation::Addx(_) if !self.halfway => self.halfway = true,
+            Operation::Addx(dx) => {
+                self.crt.x += dx;
+                self.halfway = false;
+                self.next += 1;
+            }
+            Operation::Noop => self.next += 1,
+        }
+        true
+    }
+
+    fn frame(&self) -> String {
+        let width = self.crt.screen.width() as i32;
+        let sprite: String = (0..width)
+            .map(|col| {
+                if (self.crt.x - 1..=self.crt.x + 1).contains(&col) {
+                    '='
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        format!(
+            "{}\n{sprite}\ncycle {} x {} signal {}",
+            self.crt.screen,
+            self.crt.cycle,
+            self.crt.x,
+            self.crt.recordings.iter().sum::<i32>()
+        )
+    }
+}
+
+impl<'a> Beam<'a> {
+    fn new(program: &'a [Operation]) -> Self {
+        Beam {
+            crt: CRT::default(),
+            program,
+            next: 0,
+            halfway: false,
+        }
+    }
+}
+
+impl Animate for CRT {
+    fn animate(&self, _part: u8) -> Box<dyn Stepper + '_> {
+        Box::new(Beam::new(&self.program))
+    }
+}
+
+/// Runs the program a cycle at a time on command.
+struct Console<'a>(Beam<'a>);
+
+impl Console<'_> {
+    fn status(&self) -> String {
+        let crt = &self.0.crt;
+        let signal: i32 = crt.recordings.iter().sum();
+        let op = match self.0.program.get(self.0.next) {
+            Some(Operation::Noop) => "noop".to_string(),
+            Some(Operation::Addx(dx)) => format!("addx {dx}"),
+            None => "halted".to_string(),
+        };
+        format!("cycle {} x {} signal {signal}, next {op}", crt.cycle, crt.x)
+    }
+}
+
+impl Session for Console<'_> {
+    fn help(&self) -> &'static str {
+        "step [N]          run N cycles, 1 by default
+until CYCLE       run up to a cycle
+show              draw the screen and sprite
+reset             start the program again"
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let cycles = match command {
+            "step" => repl::arg(args, 0, "cycles", 1)?,
+            "until" => {
+                let until: i32 = repl::arg(args, 0, "cycle", -1)?;
+                if until < 0 {
+                    return Err("usage: until CYCLE".to_string());
+                }
+                (until - self.0.crt.cycle).max(0)
+            }
+            "show" => return Ok(self.0.frame()),
+            "reset" => {
+                self.0 = Beam::new(self.0.program);
+                return Ok(self.status());
+            }
+            _ => return Err(repl::unknown(command)),
+        };
+        for _ in 0..cycles {
+            if !self.0.step() {
+                break;
+            }
+        }
+        Ok(self.status())
+    }
+}
+
+impl Explore for CRT {
+    fn explore(&self) -> Box<dyn Session + '_> {
+        Box::new(Console(Beam::new(&self.program)))
+    }
+}
+
+enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let expected = "an instruction `noop` or `addx N`";
+        if value == "noop" {
+            return Ok(Operation::Noop);
+        }
+        match parse::token_int(value, expected)? {
+            ("addx", dx) => Ok(Operation::Addx(dx)),
+            _ => Err(ParseError::new(value, expected)),
+        }
+    }
+}
+
+fn parse_program(ops: &str) -> Result<Vec<Operation>, ParseError> {
+    parse::lines(ops, |line| Operation::try_from(line))
+}
+
+impl Validate for CRT {
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate::lines(input, |line| Operation::try_from(line).map(|_| ()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{animate::Animate, assert_snapshot, repl::Explore, validate::Validate, Solution};
+
+    use crate::CRT;
+
+    #[test]
+    fn small_program() {
+        let mut crt = CRT::default();
+        let data = include_str!("../small_data");
+        let sum = crt.run(data).unwrap();
+
+        assert_eq!(crt.recordings, vec![420, 1140, 1800, 2940, 2880, 3960]);
+        assert_eq!(sum, 13140);
+
+        assert_snapshot!("small_data_screen", crt);
+    }
+
+    #[test]
+    fn animates() {
+        let crt = CRT::parse(include_str!("../small_data")).unwrap();
+        let mut beam = crt.animate(2);
+        let mut cycles = 0;
+        while beam.step() {
+            cycles += 1;
+        }
+        assert_eq!(cycles, 240);
+        assert!(beam.frame().ends_with("cycle 240 x 17 signal 13140"));
+
+        let (replayed, _) = crt.replay();
+        assert!(beam.frame().starts_with(&replayed.screen.to_string()));
+
+        let mut beam = crt.animate(2);
+        for _ in 0..100 {
+            beam.step();
+        }
+        assert_snapshot!("small_data_beam_cycle_100", beam.frame());
+    }
+
+    #[test]
+    fn explores() {
+        let crt = CRT::parse(include_str!("../small_data")).unwrap();
+        let mut console = crt.explore();
+        assert_eq!(
+            console.run("step", &[]),
+            Ok("cycle 1 x 1 signal 0, next addx 15".to_string())
+        );
+        assert_eq!(
+            console.run("until", &["20"]),
+            Ok("cycle 20 x 21 signal 420, next addx -1".to_string())
+        );
+        assert_eq!(
+            console.run("step", &["1000"]),
+            Ok("cycle 240 x 17 signal 13140, next halted".to_string())
+        );
+        assert_eq!(
+            console.run("reset", &[]),
+            Ok("cycle 0 x 1 signal 0, next addx 15".to_string())
+        );
+        assert!(console.run("until", &[]).is_err());
+    }
+
+    #[test]
+    fn bad_operation() {
+        let mut crt = CRT::default();
+        let error = crt.run("noop\naddx 3\naddx x").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 6));
+
+        let error = crt.run("noop\nmulx 3").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn validates_instructions() {
+        let errors = CRT::validate("noop\naddx 3\naddx\nmulx 2\naddx -x");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(found, [(3, 1), (4, 1), (5, 6)]);
+        assert!(CRT::validate(include_str!("../small_data")).is_empty());
+    }
+}
@@ -0,0 +1,77 @@
+//! Random programs, run with a full list of X for every cycle.
+
+use advent::random::Rng;
+
+/// A program that runs for exactly 240 cycles, filling the screen once.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut ops = Vec::new();
+    let mut cycles = 0;
+    while cycles < 240 {
+        if cycles == 239 || rng.chance(0.3) {
+            ops.push("noop".to_string());
+            cycles += 1;
+        } else {
+            ops.push(format!("addx {}", rng.int(-20..=20)));
+            cycles += 2;
+        }
+    }
+    ops.join("\n")
+}
+
+/// The value of X during each cycle, counting from cycle 1.
+fn register(input: &str) -> Vec<i32> {
+    let mut x = 1;
+    let mut during = vec![x];
+    for line in input.lines() {
+        match line.strip_prefix("addx ") {
+            Some(dx) => {
+                during.extend([x, x]);
+                x += dx.parse::<i32>().unwrap();
+            }
+            None => during.push(x),
+        }
+    }
+    during
+}
+
+pub fn part_one(input: &str) -> i32 {
+    let x = register(input);
+    [20, 60, 100, 140, 180, 220]
+        .iter()
+        .map(|cycle| cycle * x[*cycle as usize])
+        .sum()
+}
+
+/// The lit pixels, one screen row per line.
+pub fn part_two(input: &str) -> String {
+    let x = register(input);
+    let rows: Vec<String> = (0..6)
+        .map(|row| {
+            (0..40)
+                .map(|col| {
+                    let sprite = x[row * 40 + col + 1];
+                    if (sprite - col as i32).abs() <= 1 {
+                        '#'
+                    } else {
+                        ' '
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use advent::{random::check_reference, Image};
+
+    use crate::{reference, CRT};
+
+    #[test]
+    fn matches_reference() {
+        check_reference::<CRT>(reference::generate, reference::part_one, |input| {
+            Image(reference::part_two(input))
+        });
+    }
+}
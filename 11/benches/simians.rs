@@ -0,0 +1,13 @@
+use advent::{bench::Bencher, input::normalize, Solution};
+use simians::{Simians, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("Simians::parse", || Simians::parse(&input).unwrap());
+
+    let simians = Simians::parse(&input).unwrap();
+    bencher.bench("Simians::part_one", || simians.part_one());
+    bencher.bench("Simians::part_two", || simians.part_two());
+}
@@ -0,0 +1,45 @@
+use advent::{parse, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("../data");
+
+/// Day 11, not modelled yet: for now, the input's lines.
+pub struct Simians(Vec<String>);
+
+impl Solution for Simians {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse::lines(input, |line| Ok(line.to_string())).map(Simians)
+    }
+
+    fn part_one(&self) -> usize {
+        self.0.len()
+    }
+
+    fn part_two(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::Solution;
+
+    use crate::Simians;
+
+    // The example from the puzzle text.
+    const TEST_DATA: &str = "";
+
+    #[test]
+    fn part_1() {
+        let simians = Simians::parse(TEST_DATA).unwrap();
+        assert_eq!(simians.part_one(), 0);
+    }
+
+    #[test]
+    fn part_2() {
+        let simians = Simians::parse(TEST_DATA).unwrap();
+        assert_eq!(simians.part_two(), 0);
+    }
+}
@@ -1,3 +1,7 @@
-fn main() {
-    println!("Hello, world!");
+use std::process::ExitCode;
+
+use simians::{Simians, INPUT};
+
+fn main() -> ExitCode {
+    advent::run::<Simians>(11, INPUT)
 }
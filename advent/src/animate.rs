@@ -0,0 +1,236 @@
+//! Step-by-step playback of simulations in the terminal.
+
+use std::{
+    io::{self, stdin, stdout, BufRead, Stdout, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::Solution;
+
+/// A simulation that advances one step at a time, drawing a frame after each.
+pub trait Stepper {
+    /// Advances one step, returning false once there is nothing left to do.
+    fn step(&mut self) -> bool;
+
+    /// The current state, as text.
+    fn frame(&self) -> String;
+}
+
+/// A solution whose parts can be watched as they run.
+pub trait Animate: Solution {
+    fn animate(&self, part: u8) -> Box<dyn Stepper + '_>;
+}
+
+/// A command typed into the player, one per line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Toggle,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    pub const HELP: &'static str = "enter: step  p: play/pause  +/-: speed  q: quit";
+
+    pub fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "" | "s" => Some(Control::Step),
+            "p" => Some(Control::Toggle),
+            "+" => Some(Control::Faster),
+            "-" => Some(Control::Slower),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Reads controls from stdin on a background thread.
+pub fn controls() -> Receiver<Control> {
+    let (send, recv) = mpsc::channel();
+    thread::spawn(move || {
+        for line in stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(control) = Control::parse(&line) {
+                if send.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    recv
+}
+
+/// Draws a [`Stepper`]'s frames, playing them on a timer or one at a time.
+pub struct Player<W> {
+    out: W,
+    delay: Duration,
+    playing: bool,
+}
+
+impl Player<Stdout> {
+    pub fn new() -> Self {
+        Player::to(stdout())
+    }
+}
+
+impl Default for Player<Stdout> {
+    fn default() -> Self {
+        Player::new()
+    }
+}
+
+impl<W: Write> Player<W> {
+    const FASTEST: Duration = Duration::from_millis(1);
+    const SLOWEST: Duration = Duration::from_secs(2);
+
+    pub fn to(out: W) -> Self {
+        Player {
+            out,
+            delay: Duration::from_millis(100),
+            playing: true,
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Starts paused, waiting for a step or play.
+    pub fn paused(mut self) -> Self {
+        self.playing = false;
+        self
+    }
+
+    /// Plays `sim` until it finishes or is quit, returning how many steps ran.
+    ///
+    /// Once `controls` hangs up the rest of the simulation plays out.
+    pub fn run<S: Stepper + ?Sized>(
+        &mut self,
+        sim: &mut S,
+        controls: &Receiver<Control>,
+    ) -> io::Result<usize> {
+        let mut steps = 0;
+        let mut attached = true;
+        self.draw(sim, steps)?;
+
+        loop {
+            let control = if !attached {
+                thread::sleep(self.delay);
+                None
+            } else if self.playing {
+                match controls.recv_timeout(self.delay) {
+                    Ok(control) => Some(control),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        attached = false;
+                        None
+                    }
+                }
+            } else {
+                match controls.recv() {
+                    Ok(control) => Some(control),
+                    Err(_) => {
+                        attached = false;
+                        self.playing = true;
+                        continue;
+                    }
+                }
+            };
+
+            match control {
+                None | Some(Control::Step) => {
+                    if !sim.step() {
+                        break;
+                    }
+                    steps += 1;
+                }
+                Some(Control::Toggle) => self.playing = !self.playing,
+                Some(Control::Faster) => self.delay = (self.delay / 2).max(Self::FASTEST),
+                Some(Control::Slower) => self.delay = (self.delay * 2).min(Self::SLOWEST),
+                Some(Control::Quit) => break,
+            }
+            self.draw(sim, steps)?;
+        }
+
+        Ok(steps)
+    }
+
+    fn draw<S: Stepper + ?Sized>(&mut self, sim: &S, steps: usize) -> io::Result<()> {
+        let state = if self.playing { "playing" } else { "paused" };
+        write!(
+            self.out,
+            "\x1b[2J\x1b[H{}\n[{state}] step {steps}, {:?}/frame  {}\n",
+            sim.frame(),
+            self.delay,
+            Control::HELP
+        )?;
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::mpsc, time::Duration};
+
+    use crate::animate::{Control, Player, Stepper};
+
+    struct Counter(u32, u32);
+
+    impl Stepper for Counter {
+        fn step(&mut self) -> bool {
+            if self.0 == self.1 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+
+        fn frame(&self) -> String {
+            format!("count {}", self.0)
+        }
+    }
+
+    #[test]
+    fn steps_while_paused() {
+        let (send, recv) = mpsc::channel();
+        for control in [Control::Step, Control::Step, Control::Quit] {
+            send.send(control).unwrap();
+        }
+
+        let mut out = Vec::new();
+        let mut counter = Counter(0, 10);
+        let steps = Player::to(&mut out)
+            .paused()
+            .run(&mut counter, &recv)
+            .unwrap();
+        assert_eq!(steps, 2);
+        assert_eq!(counter.0, 2);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("count 2\n[paused] step 2"));
+    }
+
+    #[test]
+    fn plays_out() {
+        let (send, recv) = mpsc::channel();
+        send.send(Control::Faster).unwrap();
+        drop(send);
+
+        let mut counter = Counter(0, 5);
+        let mut player = Player::to(Vec::new()).delay(Duration::from_millis(2));
+        assert_eq!(player.run(&mut counter, &recv).unwrap(), 5);
+        assert_eq!(player.delay, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn parses_controls() {
+        assert_eq!(Control::parse(""), Some(Control::Step));
+        assert_eq!(Control::parse(" p\n"), Some(Control::Toggle));
+        assert_eq!(Control::parse("x"), None);
+    }
+}
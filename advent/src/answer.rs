@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+/// A puzzle answer, as it would be typed into the answer box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Letters drawn as ASCII art, to be read by a human.
+    Image(String),
+}
+
+impl Answer {
+    /// `number`, `text` or `image`, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+            Answer::Image(_) => "image",
+        }
+    }
+}
+
+/// Wraps rendered output so it converts to [`Answer::Image`].
+pub struct Image(pub String);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) | Answer::Image(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<Image> for Answer {
+    fn from(value: Image) -> Self {
+        Answer::Image(value.0)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Number(i128::try_from(value).expect("answer fits in an i128"))
+            }
+        })*
+    };
+}
+
+number!(u8, u32, u64, u128, usize, i32, i64);
+
+#[cfg(test)]
+mod test {
+    use crate::{Answer, Image};
+
+    #[test]
+    fn converts() {
+        assert_eq!(Answer::from(24933642usize), Answer::Number(24933642));
+        assert_eq!(Answer::from(-3i32), Answer::Number(-3));
+        assert_eq!(
+            Answer::from(u64::MAX as u128 * 3),
+            Answer::Number(55340232221128654845)
+        );
+        assert_eq!(
+            Answer::from(Image("#.\n.#".to_string())),
+            Answer::Image("#.\n.#".to_string())
+        );
+    }
+
+    #[test]
+    fn displays() {
+        assert_eq!(Answer::Number(13140).to_string(), "13140");
+        assert_eq!(Answer::Text("CMZ".to_string()).to_string(), "CMZ");
+    }
+}
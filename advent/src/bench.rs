@@ -0,0 +1,156 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// A small Criterion-style harness for `harness = false` bench targets.
+///
+/// Under `cargo bench` each function is warmed up, then timed over a number of
+/// samples, and the spread of per-iteration times is reported. Without
+/// `--bench`, as under `cargo test --benches`, each function runs once as a
+/// smoke test.
+pub struct Bencher {
+    measure: bool,
+    filter: Option<String>,
+    warm_up: Duration,
+    measurement: Duration,
+    samples: u32,
+}
+
+/// Per-iteration timings over every sample.
+#[derive(Debug, Clone, Copy)]
+pub struct Estimate {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Default for Bencher {
+    fn default() -> Self {
+        Bencher {
+            measure: true,
+            filter: None,
+            warm_up: Duration::from_millis(500),
+            measurement: Duration::from_secs(2),
+            samples: 50,
+        }
+    }
+}
+
+impl Bencher {
+    /// Reads `--bench` and an optional name filter, as passed by cargo.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        Bencher {
+            measure: args.iter().any(|a| a == "--bench"),
+            filter: args.into_iter().find(|a| !a.starts_with('-')),
+            ..Default::default()
+        }
+    }
+
+    pub fn bench<T>(&mut self, name: &str, mut f: impl FnMut() -> T) -> Option<Estimate> {
+        if self
+            .filter
+            .as_ref()
+            .is_some_and(|filter| !name.contains(filter))
+        {
+            return None;
+        }
+
+        if !self.measure {
+            black_box(f());
+            println!("{name:<32} ok");
+            return None;
+        }
+
+        let per_iter = self.warm(&mut f);
+        let budget = self.measurement / self.samples;
+        let iters = (budget.as_nanos() / per_iter.as_nanos().max(1)).max(1) as u32;
+
+        let mut times: Vec<Duration> = (0..self.samples)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    black_box(f());
+                }
+                start.elapsed() / iters
+            })
+            .collect();
+        times.sort();
+
+        let estimate = Estimate::of(&times);
+        println!(
+            "{name:<32} time: [{:>10.3?} {:>10.3?} {:>10.3?}] ± {:.3?}",
+            estimate.min, estimate.median, estimate.max, estimate.std_dev
+        );
+        Some(estimate)
+    }
+
+    /// Runs `f` for the warm up period, returning the average time per call.
+    fn warm<T>(&self, f: &mut impl FnMut() -> T) -> Duration {
+        let start = Instant::now();
+        let mut iters = 0u32;
+        while start.elapsed() < self.warm_up {
+            black_box(f());
+            iters += 1;
+        }
+        start.elapsed() / iters
+    }
+}
+
+impl Estimate {
+    /// Summarizes sorted sample times.
+    fn of(times: &[Duration]) -> Self {
+        let n = times.len() as f64;
+        let mean = times.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = times
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        Estimate {
+            min: times[0],
+            median: times[times.len() / 2],
+            mean: Duration::from_secs_f64(mean),
+            max: times[times.len() - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::bench::{Bencher, Estimate};
+
+    #[test]
+    fn estimates() {
+        let times: Vec<Duration> = [1, 2, 3, 4, 10].map(Duration::from_millis).to_vec();
+        let estimate = Estimate::of(&times);
+        assert_eq!(estimate.min, Duration::from_millis(1));
+        assert_eq!(estimate.median, Duration::from_millis(3));
+        assert_eq!(estimate.mean, Duration::from_millis(4));
+        assert_eq!(estimate.max, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn measures() {
+        let mut bencher = Bencher {
+            warm_up: Duration::from_millis(5),
+            measurement: Duration::from_millis(20),
+            samples: 5,
+            ..Default::default()
+        };
+        let estimate = bencher.bench("sum", || (0..1000u64).sum::<u64>()).unwrap();
+        assert!(estimate.min <= estimate.median && estimate.median <= estimate.max);
+
+        let mut bencher = Bencher {
+            filter: Some("scenic".to_string()),
+            ..bencher
+        };
+        assert!(bencher.bench("number_visible", || 0).is_none());
+    }
+}
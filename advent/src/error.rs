@@ -0,0 +1,110 @@
+use std::{error::Error, fmt::Display};
+
+/// Where and why a puzzle input failed to parse.
+///
+/// Lines and columns count from 1. Parsers working on part of a line report
+/// columns relative to what they were given, and callers [`shift`](Self::shift)
+/// them to the enclosing line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    /// Moves the error right by `columns`, for text that started partway through the line.
+    pub fn shift(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+
+    /// Moves the error down by `lines`, for text that started partway through the input.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Shows the offending line from `input`, pointing at the error.
+    pub fn report(&self, input: &str) -> String {
+        let source = input
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or_default();
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let caret = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "{self}\n{gutter} |\n{number} | {source}\n{gutter} | {:>width$}",
+            caret,
+            width = self.column.saturating_sub(1) + caret.len(),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found `{}`",
+            self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use crate::{assert_snapshot, ParseError};
+
+    #[test]
+    fn displays() {
+        let error = ParseError::new("Q", "one of `A`, `B`, `C`").on_line(3);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected one of `A`, `B`, `C`, found `Q`"
+        );
+    }
+
+    #[test]
+    fn reports() {
+        let error = ParseError::new("x", "a number").shift(4).on_line(2);
+        assert_eq!(
+            error.report("2-4,6-8\n2-3,x-5"),
+            "line 2, column 5: expected a number, found `x`
+  |
+2 | 2-3,x-5
+  |     ^"
+        );
+    }
+
+    #[test]
+    fn reports_wide_gutters() {
+        let input = "$ cd /\n$ ls\n".repeat(5) + "$ cd missing";
+        let error = ParseError::new("missing", "a directory listed by `ls`")
+            .shift(5)
+            .on_line(11);
+        assert_snapshot!("report_line_11", error.report(&input));
+    }
+}
@@ -0,0 +1,70 @@
+use std::{
+    fs,
+    io::{self, stdin, Read},
+    path::PathBuf,
+};
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// The `data` file compiled into the day's crate.
+    Embedded,
+}
+
+impl Source {
+    /// `-` reads stdin, no argument uses the embedded input, anything else is a path.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Embedded,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+        }
+    }
+
+    pub fn read(&self, embedded: &str) -> io::Result<String> {
+        let raw = match self {
+            Source::File(path) => fs::read_to_string(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?,
+            Source::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                input
+            }
+            Source::Embedded => embedded.to_string(),
+        };
+        Ok(normalize(&raw))
+    }
+}
+
+/// Unix line endings, with no trailing newline.
+pub fn normalize(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::input::{normalize, Source};
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(normalize("A Y\r\nB X\r\nC Z\r\n"), "A Y\nB X\nC Z");
+        assert_eq!(normalize("1000\n2000\n\n3000\n\n"), "1000\n2000\n\n3000");
+        assert_eq!(normalize("noop"), "noop");
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(Source::from_arg(None), Source::Embedded);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("small_data")),
+            Source::File("small_data".into())
+        );
+        assert_eq!(Source::Embedded.read("R 4\r\nU 4\n").unwrap(), "R 4\nU 4");
+    }
+}
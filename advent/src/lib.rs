@@ -0,0 +1,116 @@
+pub mod animate;
+mod answer;
+pub mod bench;
+mod error;
+pub mod input;
+pub mod log;
+pub mod output;
+pub mod parse;
+pub mod random;
+pub mod repl;
+pub mod snapshot;
+pub mod validate;
+
+use std::{error::Error, io::stdout, process::ExitCode, time::Instant};
+
+use input::Source;
+use output::{Format, Record};
+
+pub use answer::{Answer, Image};
+pub use error::ParseError;
+
+/// A day's puzzle: a model parsed once from the input, answering both parts.
+pub trait Solution: Sized {
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part_one(&self) -> Self::PartOne;
+
+    fn part_two(&self) -> Self::PartTwo;
+}
+
+/// Parses `input` once and answers each of `parts`, timing both.
+///
+/// # Panics
+///
+/// If a part is not 1 or 2.
+pub fn solve<S: Solution>(day: u8, parts: &[u8], input: &str) -> Result<Vec<Record>, ParseError> {
+    let start = Instant::now();
+    let solution = {
+        let _parse = span!("parse");
+        S::parse(input)?
+    };
+    let parse = start.elapsed();
+
+    let records = parts
+        .iter()
+        .map(|&part| {
+            let _part = span!("part {part}");
+            let start = Instant::now();
+            let answer = match part {
+                1 => solution.part_one().into(),
+                2 => solution.part_two().into(),
+                _ => panic!("part must be 1 or 2, got {part}"),
+            };
+            Record {
+                day,
+                part,
+                answer,
+                parse,
+                solve: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(records)
+}
+
+const USAGE: &str = "usage: [PATH|-] [--part 1|2] [--format text|json|csv]";
+
+/// The whole of a day's binary: reads the input named on the command line,
+/// or the embedded one, and prints the answers.
+pub fn run<S: Solution>(day: u8, embedded: &str) -> ExitCode {
+    let _day = span!("day {day}");
+    match try_run::<S>(day, embedded) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_run<S: Solution>(day: u8, embedded: &str) -> Result<(), Box<dyn Error>> {
+    let mut source = None;
+    let mut parts = vec![1, 2];
+    let mut format = Format::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or(format!("missing value for {arg}\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--part" => match value()?.as_str() {
+                "1" => parts = vec![1],
+                "2" => parts = vec![2],
+                _ => return Err(format!("--part must be 1 or 2\n{USAGE}").into()),
+            },
+            "--format" => format = value()?.parse()?,
+            _ if source.is_none() && (arg == "-" || !arg.starts_with('-')) => source = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}").into()),
+        }
+    }
+
+    let input = Source::from_arg(source.as_deref()).read(embedded)?;
+    let records = solve::<S>(day, &parts, &input).map_err(|e| e.report(&input))?;
+
+    let mut out = stdout().lock();
+    format.header(&mut out)?;
+    for record in records.iter() {
+        format.write(record, &mut out)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,246 @@
+//! Leveled logging to stderr, filtered per module by the `AOC_LOG` environment variable.
+//!
+//! `AOC_LOG` is a comma-separated list of directives: a bare level sets the
+//! default, and `module=level` overrides it for that module and everything
+//! inside it, as in `AOC_LOG=info,rucksack=trace`. Without it only warnings
+//! and errors are shown. Messages below the filter are never formatted.
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Display},
+    str::FromStr,
+    sync::OnceLock,
+    time::Instant,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Only meaningful in a filter, where it silences everything.
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" => Ok(Level::Off),
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level `{s}`")),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "OFF",
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// The most verbose level shown, by default and per module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Level,
+    modules: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter {
+            default: Level::Warn,
+            modules: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Filter::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                None => filter.default = directive.parse()?,
+                // Crate names use `-`, module paths `_`.
+                Some((module, level)) => filter
+                    .modules
+                    .push((module.trim().replace('-', "_"), level.trim().parse()?)),
+            }
+        }
+        Ok(filter)
+    }
+
+    /// The level for `module`, from the most specific directive that covers it.
+    pub fn level(&self, module: &str) -> Level {
+        self.modules
+            .iter()
+            .filter(|(prefix, _)| {
+                module
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max(&self) -> Level {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+static FILTER: OnceLock<(Filter, Level)> = OnceLock::new();
+
+/// Sets the filter, unless logging has already started. Otherwise it is read
+/// from `AOC_LOG` on first use.
+pub fn init(filter: Filter) -> bool {
+    let max = filter.max();
+    FILTER.set((filter, max)).is_ok()
+}
+
+fn filter() -> &'static (Filter, Level) {
+    FILTER.get_or_init(|| {
+        let spec = std::env::var("AOC_LOG").unwrap_or_default();
+        let filter = Filter::parse(&spec).unwrap_or_else(|e| {
+            eprintln!("AOC_LOG: {e}");
+            Filter::default()
+        });
+        let max = filter.max();
+        (filter, max)
+    })
+}
+
+pub fn enabled(level: Level, module: &str) -> bool {
+    let (filter, max) = filter();
+    level != Level::Off && level <= *max && level <= filter.level(module)
+}
+
+thread_local! {
+    static SPANS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let spans = SPANS.with(|spans| spans.borrow().join(" > "));
+    if spans.is_empty() {
+        eprintln!("{level:<5} {module}: {args}");
+    } else {
+        eprintln!("{level:<5} {module} [{spans}]: {args}");
+    }
+}
+
+/// Names the work in progress on this thread; messages logged while it is
+/// alive are tagged with it, and its duration is logged at debug when dropped.
+#[must_use = "the span closes as soon as it is dropped"]
+pub struct Span {
+    module: &'static str,
+    start: Instant,
+}
+
+impl Span {
+    pub fn enter(module: &'static str, name: String) -> Self {
+        SPANS.with(|spans| spans.borrow_mut().push(name));
+        Span {
+            module,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if enabled(Level::Debug, self.module) {
+            write(
+                Level::Debug,
+                self.module,
+                format_args!("finished in {:?}", self.start.elapsed()),
+            );
+        }
+        SPANS.with(|spans| spans.borrow_mut().pop());
+    }
+}
+
+/// Logs a message at `level` if the filter lets it through.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+/// Opens a [`Span`](crate::log::Span) named with `format!` arguments.
+#[macro_export]
+macro_rules! span {
+    ($($arg:tt)+) => { $crate::log::Span::enter(module_path!(), format!($($arg)+)) };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::log::{Filter, Level};
+
+    #[test]
+    fn parses_filters() {
+        let filter = Filter::parse("info, folder-size=trace,advent::parse=off").unwrap();
+        assert_eq!(filter.level("rucksack"), Level::Info);
+        assert_eq!(filter.level("folder_size"), Level::Trace);
+        assert_eq!(filter.level("folder_size::fs"), Level::Trace);
+        assert_eq!(filter.level("folder_sizes"), Level::Info);
+        assert_eq!(filter.level("advent::parse"), Level::Off);
+        assert_eq!(filter.max(), Level::Trace);
+
+        assert_eq!(Filter::parse("").unwrap(), Filter::default());
+        assert!(Filter::parse("rope=loud").is_err());
+    }
+
+    #[test]
+    fn most_specific_wins() {
+        let filter = Filter::parse("advent=debug,advent::bench=error,off").unwrap();
+        assert_eq!(filter.level("advent::bench"), Level::Error);
+        assert_eq!(filter.level("advent::input"), Level::Debug);
+        assert_eq!(filter.level("crt"), Level::Off);
+    }
+}
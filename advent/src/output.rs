@@ -0,0 +1,174 @@
+use std::{
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::Answer;
+
+/// One part's answer, with how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    /// Parsing the input, shared by both parts of a run.
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// How records are printed: bare answers for people, JSON lines or CSV for scripts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("format must be text, json or csv, got `{s}`")),
+        }
+    }
+}
+
+impl Format {
+    /// Written once before any records.
+    pub fn header(&self, out: &mut impl Write) -> io::Result<()> {
+        match self {
+            Format::Csv => writeln!(out, "day,part,type,answer,parse_ns,solve_ns"),
+            Format::Text | Format::Json => Ok(()),
+        }
+    }
+
+    pub fn write(&self, record: &Record, out: &mut impl Write) -> io::Result<()> {
+        let Record {
+            day,
+            part,
+            answer,
+            parse,
+            solve,
+        } = record;
+        let (parse, solve) = (parse.as_nanos(), solve.as_nanos());
+        match self {
+            Format::Text => writeln!(out, "{answer}"),
+            Format::Json => {
+                let value = match answer {
+                    Answer::Number(n) => n.to_string(),
+                    Answer::Text(s) | Answer::Image(s) => json_string(s),
+                };
+                writeln!(
+                    out,
+                    r#"{{"day":{day},"part":{part},"type":"{}","answer":{value},"parse_ns":{parse},"solve_ns":{solve}}}"#,
+                    answer.kind()
+                )
+            }
+            Format::Csv => writeln!(
+                out,
+                "{day},{part},{},{},{parse},{solve}",
+                answer.kind(),
+                csv_field(&answer.to_string())
+            ),
+        }
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quotes a field if it holds a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        output::{Format, Record},
+        Answer,
+    };
+
+    fn write(format: Format, records: &[Record]) -> String {
+        let mut out = Vec::new();
+        format.header(&mut out).unwrap();
+        for record in records {
+            format.write(record, &mut out).unwrap();
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 10,
+                part: 1,
+                answer: Answer::Number(13140),
+                parse: Duration::from_micros(2),
+                solve: Duration::from_nanos(1500),
+            },
+            Record {
+                day: 10,
+                part: 2,
+                answer: Answer::Image("## \"#\"\n#,\n".to_string()),
+                parse: Duration::from_micros(2),
+                solve: Duration::from_nanos(900),
+            },
+        ]
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(write(Format::Text, &records()), "13140\n## \"#\"\n#,\n\n");
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            write(Format::Json, &records()),
+            r###"{"day":10,"part":1,"type":"number","answer":13140,"parse_ns":2000,"solve_ns":1500}
+{"day":10,"part":2,"type":"image","answer":"## \"#\"\n#,\n","parse_ns":2000,"solve_ns":900}
+"###
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            write(Format::Csv, &records()),
+            "day,part,type,answer,parse_ns,solve_ns
+10,1,number,13140,2000,1500
+10,2,image,\"## \"\"#\"\"
+#,
+\",2000,900
+"
+        );
+    }
+}
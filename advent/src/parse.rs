@@ -0,0 +1,172 @@
+//! Combinators for the line-oriented puzzle formats.
+//!
+//! Each parser reports errors relative to the text it was handed, and the
+//! combinators shift them so the final [`ParseError`] points into the input.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Parses every non-blank line of `input` with `f`.
+///
+/// Lines are trimmed before `f` sees them; errors are moved to the line and
+/// past any indent.
+pub fn lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let indent = line.len() - line.trim_start().len();
+            f(line.trim()).map_err(|e| e.shift(indent).shift_lines(i))
+        })
+        .collect()
+}
+
+/// Parses each run of lines between blank lines with `f`.
+///
+/// `f` sees the record's text, and any line it reports is counted from the
+/// record's first line.
+pub fn records<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut out = Vec::new();
+    // Byte offset and line index of the record being read.
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((at, first)) = start.take() {
+                out.push(f(&input[at..offset]).map_err(|e| e.shift_lines(first))?);
+            }
+        } else if start.is_none() {
+            start = Some((offset, i));
+        }
+        offset += line.len();
+    }
+    if let Some((at, first)) = start {
+        out.push(f(&input[at..]).map_err(|e| e.shift_lines(first))?);
+    }
+
+    Ok(out)
+}
+
+/// Parses each character of `text` with `f`, reporting errors at its column.
+pub fn chars<T>(
+    text: &str,
+    mut f: impl FnMut(char) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    text.char_indices()
+        .map(|(i, c)| f(c).map_err(|e| e.shift(i)))
+        .collect()
+}
+
+/// A number, like `584` or `-11`.
+pub fn int<T: FromStr>(text: &str, expected: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::new(text, expected))
+}
+
+/// Splits `text` at the first `sep`, reporting `expected` if there is none.
+pub fn split<'a>(
+    text: &'a str,
+    sep: &str,
+    expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(sep)
+        .ok_or_else(|| ParseError::new(text, expected))
+}
+
+/// Splits `text` at the first `sep` and parses each side, so errors on the
+/// right point past the separator.
+pub fn both<'a, A, B>(
+    text: &'a str,
+    sep: &str,
+    expected: &str,
+    left: impl FnOnce(&'a str) -> Result<A, ParseError>,
+    right: impl FnOnce(&'a str) -> Result<B, ParseError>,
+) -> Result<(A, B), ParseError> {
+    let (a, b) = split(text, sep, expected)?;
+    let column = a.len() + sep.len();
+    Ok((left(a)?, right(b).map_err(|e| e.shift(column))?))
+}
+
+/// Two numbers around `sep`, like `2-4`.
+pub fn int_pair<T: FromStr>(text: &str, sep: &str, expected: &str) -> Result<(T, T), ParseError> {
+    both(
+        text,
+        sep,
+        expected,
+        |a| int(a, "a number"),
+        |b| int(b, "a number"),
+    )
+}
+
+/// A word then a number, like `R 4` or `addx -11`.
+pub fn token_int<'a, T: FromStr>(
+    text: &'a str,
+    expected: &str,
+) -> Result<(&'a str, T), ParseError> {
+    both(text, " ", expected, Ok, |n| int(n, "a number"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::{chars, int, int_pair, lines, records, token_int};
+    use crate::ParseError;
+
+    #[test]
+    fn numbered_lines() {
+        let sums = lines("1-2\n\n  3-4\n", |l| {
+            int_pair::<u32>(l, "-", "a pair").map(|(a, b)| a + b)
+        });
+        assert_eq!(sums, Ok(vec![3, 7]));
+
+        let error = lines("1-2\n\n  3-x\n", |l| int_pair::<u32>(l, "-", "a pair"))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 5));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn grouped_records() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000";
+        let totals = records(input, |r| {
+            Ok(lines(r, |l| int::<u32>(l, "a count"))?.iter().sum())
+        });
+        assert_eq!(totals, Ok(vec![3000, 4000, 11000]));
+
+        let error = records("1\n\n\n2\nthree\n", |r| {
+            lines(r, |l| int::<u32>(l, "a count"))
+        })
+        .err()
+        .unwrap();
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(token_int("addx -11", "an instruction"), Ok(("addx", -11)));
+
+        let error = token_int::<usize>("U four", "a move").err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (3, "four"));
+
+        let error = token_int::<usize>("U4", "a move").err().unwrap();
+        assert_eq!(error.expected, "a move");
+    }
+
+    #[test]
+    fn columns() {
+        let digit = |c: char| {
+            c.to_digit(10)
+                .ok_or(ParseError::new(&c.to_string(), "a digit"))
+        };
+        assert_eq!(chars("303", digit), Ok(vec![3, 0, 3]));
+        assert_eq!(chars("30x", digit).err().unwrap().column, 3);
+    }
+}
@@ -0,0 +1,148 @@
+use std::ops::RangeInclusive;
+
+use crate::{Answer, Solution};
+
+/// A small seeded generator (SplitMix64) for building random puzzle inputs.
+///
+/// The same seed always gives the same sequence, so a failing property test
+/// can be replayed from the seed it reports.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        self.next_u64() % n
+    }
+
+    pub fn usize(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (lo, hi) = range.into_inner();
+        lo + self.below((hi - lo) as u64 + 1) as usize
+    }
+
+    pub fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        lo + self.below(hi.abs_diff(lo) + 1) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.usize(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.usize(0..=i));
+        }
+    }
+}
+
+/// Solves the inputs `generate` makes from seeds `0..200` and checks both
+/// parts against the answers of a slow but obviously correct reference,
+/// naming the seed of the first that differs.
+pub fn check_reference<S: Solution>(
+    generate: impl Fn(&mut Rng) -> String,
+    part_one: impl Fn(&str) -> S::PartOne,
+    part_two: impl Fn(&str) -> S::PartTwo,
+) {
+    for seed in 0..200 {
+        let input = generate(&mut Rng::new(seed));
+        let solution = S::parse(&input).unwrap_or_else(|e| panic!("seed {seed}: {e}"));
+        let (one, two): (Answer, Answer) = (solution.part_one().into(), solution.part_two().into());
+        assert_eq!(one, part_one(&input).into(), "part one, seed {seed}");
+        assert_eq!(two, part_two(&input).into(), "part two, seed {seed}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        random::{check_reference, Rng},
+        ParseError, Solution,
+    };
+
+    /// Counts lines, or the characters in them.
+    struct Lines(Vec<String>);
+
+    impl Solution for Lines {
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            Ok(Lines(input.lines().map(str::to_string).collect()))
+        }
+
+        fn part_one(&self) -> usize {
+            self.0.len()
+        }
+
+        fn part_two(&self) -> usize {
+            self.0.iter().map(|line| line.len()).sum()
+        }
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        let lines: Vec<String> = (0..rng.usize(1..=5))
+            .map(|_| "x".repeat(rng.usize(0..=3)))
+            .collect();
+        lines.join("\n")
+    }
+
+    #[test]
+    fn checks_reference() {
+        let chars = |input: &str| input.chars().filter(|&c| c != '\n').count();
+        check_reference::<Lines>(generate, |input| input.lines().count(), chars);
+    }
+
+    #[test]
+    #[should_panic(expected = "part two, seed 0")]
+    fn reports_seed() {
+        check_reference::<Lines>(generate, |input| input.lines().count(), |_| 99);
+    }
+
+    #[test]
+    fn repeats() {
+        let a: Vec<u64> = (0..4)
+            .scan(Rng::new(7), |r, _| Some(r.next_u64()))
+            .collect();
+        let b: Vec<u64> = (0..4)
+            .scan(Rng::new(7), |r, _| Some(r.next_u64()))
+            .collect();
+        assert_eq!(a, b);
+        assert_ne!(Rng::new(8).next_u64(), a[0]);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            let n = rng.int(-2..=2);
+            assert!((-2..=2).contains(&n));
+            seen[(n + 2) as usize] = true;
+        }
+        assert_eq!(seen, [true; 5]);
+
+        let mut items = [1, 2, 3, 4, 5, 6];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+    }
+}
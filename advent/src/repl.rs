@@ -0,0 +1,160 @@
+//! A read-eval-print loop for poking at a parsed puzzle.
+
+use std::{
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{Answer, Solution};
+
+/// Commands over a model's state, which may change as they run.
+pub trait Session {
+    /// One line per command: its usage, then what it does.
+    fn help(&self) -> &'static str;
+
+    /// Runs `command`, returning what to print.
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// A solution that can be explored interactively.
+pub trait Explore: Solution {
+    fn explore(&self) -> Box<dyn Session + '_>;
+}
+
+const BUILTIN_HELP: &str = "part 1|2          answer a part
+help              show this
+quit              leave";
+
+/// The `i`th argument parsed as a `T`, or `default` if there are fewer.
+pub fn arg<T: FromStr>(args: &[&str], i: usize, name: &str, default: T) -> Result<T, String> {
+    match args.get(i) {
+        None => Ok(default),
+        Some(arg) => arg
+            .parse()
+            .map_err(|_| format!("{name} must be a number, got `{arg}`")),
+    }
+}
+
+/// The error for a command a session does not know.
+pub fn unknown(command: &str) -> String {
+    format!("unknown command `{command}`, try `help`")
+}
+
+/// Reads commands from `input` until it ends or `quit`, writing results to `out`.
+///
+/// Errors from a command are printed and the loop carries on. With `prompt`
+/// set, a `> ` is written before each line is read.
+pub fn repl<S: Explore>(
+    solution: &S,
+    input: impl BufRead,
+    mut out: impl Write,
+    prompt: bool,
+) -> io::Result<()> {
+    let mut session = solution.explore();
+    let mut lines = input.lines();
+    loop {
+        if prompt {
+            write!(out, "> ")?;
+            out.flush()?;
+        }
+        let Some(line) = lines.next() else { break };
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            continue;
+        };
+
+        let result = match command {
+            "quit" | "exit" => break,
+            "help" => Ok(format!("{}\n{BUILTIN_HELP}", session.help())),
+            "part" => {
+                let answer: Result<Answer, _> = match args {
+                    ["1"] => Ok(solution.part_one().into()),
+                    ["2"] => Ok(solution.part_two().into()),
+                    _ => Err("usage: part 1|2".to_string()),
+                };
+                answer.map(|answer| answer.to_string())
+            }
+            _ => session.run(command, args),
+        };
+        match result {
+            Ok(text) if text.is_empty() => {}
+            Ok(text) => writeln!(out, "{text}")?,
+            Err(e) => writeln!(out, "error: {e}")?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        repl::{arg, repl, unknown, Explore, Session},
+        ParseError, Solution,
+    };
+
+    struct Start(u32);
+
+    impl Solution for Start {
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            crate::parse::int(input, "a number").map(Start)
+        }
+
+        fn part_one(&self) -> u32 {
+            self.0
+        }
+
+        fn part_two(&self) -> u32 {
+            self.0 * 2
+        }
+    }
+
+    struct Counter(u32);
+
+    impl Session for Counter {
+        fn help(&self) -> &'static str {
+            "add [N]           count up"
+        }
+
+        fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+            match command {
+                "add" => {
+                    self.0 += arg(args, 0, "N", 1)?;
+                    Ok(self.0.to_string())
+                }
+                _ => Err(unknown(command)),
+            }
+        }
+    }
+
+    impl Explore for Start {
+        fn explore(&self) -> Box<dyn Session + '_> {
+            Box::new(Counter(self.0))
+        }
+    }
+
+    #[test]
+    fn runs_commands() {
+        let start = Start::parse("5").unwrap();
+        let input = "add\n\n  add 3 \nadd x\npart 2\nnope\nquit\nadd\n";
+        let mut out = Vec::new();
+        repl(&start, input.as_bytes(), &mut out, false).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "6\n9\nerror: N must be a number, got `x`\n10\nerror: unknown command `nope`, try `help`\n"
+        );
+    }
+
+    #[test]
+    fn prompts() {
+        let start = Start::parse("1").unwrap();
+        let mut out = Vec::new();
+        repl(&start, "help".as_bytes(), &mut out, true).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("> add [N]"));
+        assert!(out.ends_with("quit              leave\n> "));
+    }
+}
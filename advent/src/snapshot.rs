@@ -0,0 +1,128 @@
+//! Snapshot tests for rendered output.
+//!
+//! [`assert_snapshot!`](crate::assert_snapshot) compares a rendering against
+//! `snapshots/NAME.snap` in the calling crate, byte for byte. A missing or
+//! changed snapshot fails the test with a diff; run the tests with
+//! `UPDATE_SNAPSHOTS=1` to record the new renderings instead, then review
+//! them in `git diff`.
+
+use std::{fs, path::Path};
+
+/// Set to anything but `0` to write snapshots rather than check them.
+pub const UPDATE: &str = "UPDATE_SNAPSHOTS";
+
+fn updating() -> bool {
+    std::env::var(UPDATE).is_ok_and(|value| value != "0")
+}
+
+/// Compares two texts line by line: unchanged lines are indented, changed
+/// ones shown as `-` then `+`.
+pub fn diff(old: &str, new: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (old.lines().collect(), new.lines().collect());
+    let mut out = String::new();
+    for i in 0..old.len().max(new.len()) {
+        match (old.get(i), new.get(i)) {
+            (Some(a), Some(b)) if a == b => out += &format!("  {a}\n"),
+            (a, b) => {
+                if let Some(a) = a {
+                    out += &format!("- {a}\n");
+                }
+                if let Some(b) = b {
+                    out += &format!("+ {b}\n");
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Checks `actual` against the snapshot at `path`, or writes it there when
+/// `update` is set.
+pub fn check(path: &Path, actual: &str, update: bool) -> Result<(), String> {
+    let name = path.display();
+    let expected = fs::read_to_string(path).ok();
+    if expected.as_deref() == Some(actual) {
+        return Ok(());
+    }
+    if update {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        }
+        return fs::write(path, actual).map_err(|e| format!("{name}: {e}"));
+    }
+
+    match expected {
+        None => Err(format!(
+            "no snapshot at {name}, run with {UPDATE}=1 to record:\n{actual}"
+        )),
+        Some(expected) => {
+            let mut message = format!(
+                "{name} does not match (- snapshot, + actual), run with {UPDATE}=1 to accept:\n{}",
+                diff(&expected, actual)
+            );
+            if expected.lines().eq(actual.lines()) {
+                message += "(only the line endings differ)\n";
+            }
+            Err(message)
+        }
+    }
+}
+
+/// Panics unless `actual` matches `dir/name.snap`; see the [module docs](self).
+#[track_caller]
+pub fn assert(dir: &str, name: &str, actual: &str) {
+    let path = Path::new(dir).join(format!("{name}.snap"));
+    if let Err(message) = check(&path, actual, updating()) {
+        panic!("{message}");
+    }
+}
+
+/// Asserts that a rendering, anything [`Display`](std::fmt::Display), matches
+/// the crate's `snapshots/NAME.snap`.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::assert(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots"),
+            $name,
+            &::std::string::ToString::to_string(&$actual),
+        )
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::snapshot::{check, diff};
+
+    #[test]
+    fn diffs() {
+        assert_eq!(diff("42\n", "42\n"), "  42\n");
+        assert_eq!(diff("41\n", "42\n"), "- 41\n+ 42\n");
+        assert_eq!(diff("#.\n.#\n", "#.\n##\n..\n"), "  #.\n- .#\n+ ##\n+ ..\n");
+    }
+
+    #[test]
+    fn records_and_checks() {
+        let dir = std::env::temp_dir().join(format!("advent-snapshot-{}", std::process::id()));
+        let path = dir.join("screen.snap");
+
+        let error = check(&path, "#.\n.#\n", false).err().unwrap();
+        assert!(error.starts_with("no snapshot at"));
+
+        check(&path, "#.\n.#\n", true).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+        check(&path, "#.\n.#\n", false).unwrap();
+
+        let error = check(&path, "#.\n##\n", false).err().unwrap();
+        assert!(error.ends_with("  #.\n- .#\n+ ##\n"), "{error}");
+        let error = check(&path, "#.\n.#", false).err().unwrap();
+        assert!(
+            error.ends_with("(only the line endings differ)\n"),
+            "{error}"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
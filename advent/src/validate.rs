@@ -0,0 +1,93 @@
+//! Checks an input against a day's grammar, finding every problem rather
+//! than stopping at the first like [`Solution::parse`] does.
+
+use crate::{ParseError, Solution};
+
+/// A solution whose input format can be checked line by line.
+pub trait Validate: Solution {
+    /// Every problem in `input`, in order.
+    fn validate(input: &str) -> Vec<ParseError>;
+}
+
+/// The first problem only, for days without a [`Validate`] grammar.
+pub fn parse_only<S: Solution>(input: &str) -> Vec<ParseError> {
+    S::parse(input).err().into_iter().collect()
+}
+
+/// Checks every non-blank line of `input` with `f`, collecting its errors.
+///
+/// Like [`parse::lines`](crate::parse::lines), `f` sees the trimmed line and
+/// errors are moved to where it sits in the input.
+pub fn lines(input: &str, mut f: impl FnMut(&str) -> Result<(), ParseError>) -> Vec<ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(i, line)| {
+            let indent = line.len() - line.trim_start().len();
+            f(line.trim()).err().map(|e| e.shift(indent).shift_lines(i))
+        })
+        .collect()
+}
+
+/// Each problem shown against its line, then a count.
+pub fn report(errors: &[ParseError], input: &str) -> String {
+    let lines = input.lines().filter(|line| !line.trim().is_empty()).count();
+    let mut out = String::new();
+    for error in errors {
+        out += &error.report(input);
+        out += "\n\n";
+    }
+    let plural = |n: usize, word: &str| match n {
+        1 => format!("1 {word}"),
+        n => format!("{n} {word}s"),
+    };
+    out += &format!("{}, ", plural(lines, "line"));
+    out += &match errors.len() {
+        0 => "no problems".to_string(),
+        n => plural(n, "problem"),
+    };
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        parse,
+        validate::{lines, report},
+        ParseError,
+    };
+
+    fn even(line: &str) -> Result<(), ParseError> {
+        let n: u32 = parse::int(line, "a number")?;
+        match n % 2 {
+            0 => Ok(()),
+            _ => Err(ParseError::new(line, "an even number")),
+        }
+    }
+
+    #[test]
+    fn finds_every_problem() {
+        let errors = lines("2\nx\n\n  3\n4\n", even);
+        let found: Vec<_> = errors
+            .iter()
+            .map(|e| (e.line, e.column, e.expected.as_str()))
+            .collect();
+        assert_eq!(found, [(2, 1, "a number"), (4, 3, "an even number")]);
+    }
+
+    #[test]
+    fn reports() {
+        let input = "2\nx\n4";
+        assert_eq!(report(&lines("2\n4", even), "2\n4"), "2 lines, no problems");
+        assert_eq!(
+            report(&lines(input, even), input),
+            "line 2, column 1: expected a number, found `x`
+  |
+2 | x
+  | ^
+
+3 lines, 1 problem"
+        );
+    }
+}
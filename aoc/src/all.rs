@@ -0,0 +1,150 @@
+use std::{
+    error::Error,
+    io::{stdout, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent::{
+    input::Source,
+    output::{Format, Record},
+    span, Answer,
+};
+
+use crate::{days::Day, verify::escape};
+
+/// Runs `f` on every job across `threads` workers, returning the results in
+/// job order.
+pub fn pool<T: Sync, R: Send>(jobs: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(jobs.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else { break };
+                let result = f(job);
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Runs both parts of every day on its embedded input, each part parsing
+/// its own copy, and reports the answers and timings.
+///
+/// Returns false if any day failed to parse its input.
+pub fn run_all(threads: usize, budget: Duration, format: Format) -> Result<bool, Box<dyn Error>> {
+    let jobs: Vec<(&Day, u8)> = crate::days::DAYS
+        .iter()
+        .flat_map(|day| [(day, 1), (day, 2)])
+        .collect();
+
+    let start = Instant::now();
+    let results = pool(&jobs, threads, |&(day, part)| {
+        let _day = span!("day {} part {part}", day.day);
+        let input = Source::Embedded
+            .read(day.input)
+            .map_err(|e| e.to_string())?;
+        day.solve(&[part], &input)
+            .map(|mut records| records.remove(0))
+    });
+    let wall = start.elapsed();
+
+    let mut out = stdout().lock();
+    if format != Format::Text {
+        format.header(&mut out)?;
+        for record in results.iter().flatten() {
+            format.write(record, &mut out)?;
+        }
+        return Ok(results.iter().all(Result::is_ok));
+    }
+
+    let answers: Vec<String> = results
+        .iter()
+        .map(|result| match result {
+            Ok(Record {
+                answer: Answer::Image(_),
+                ..
+            }) => "(image below)".to_string(),
+            Ok(record) => escape(&record.answer.to_string()),
+            Err(_) => "ERROR".to_string(),
+        })
+        .collect();
+    let width = answers.iter().map(String::len).max().unwrap_or(0).max(6);
+
+    writeln!(
+        out,
+        "{:>3} {:>4} {:<width$} {:>11} {:>11}",
+        "day", "part", "answer", "parse", "solve"
+    )?;
+    let mut slow = 0;
+    for ((&(day, part), result), answer) in jobs.iter().zip(results.iter()).zip(answers.iter()) {
+        let Ok(record) = result else {
+            writeln!(out, "{:>3} {part:>4} {answer}", day.day)?;
+            continue;
+        };
+        let flag = if record.parse + record.solve > budget {
+            slow += 1;
+            "  SLOW"
+        } else {
+            ""
+        };
+        writeln!(
+            out,
+            "{:>3} {part:>4} {answer:<width$} {:>11.3?} {:>11.3?}{flag}",
+            day.day, record.parse, record.solve
+        )?;
+    }
+
+    for (&(day, part), result) in jobs.iter().zip(results.iter()) {
+        match result {
+            Ok(Record {
+                answer: Answer::Image(image),
+                ..
+            }) => writeln!(out, "\nday {} part {part}:\n{image}", day.day)?,
+            Err(e) => writeln!(out, "\nday {} part {part} failed:\n{e}", day.day)?,
+            Ok(_) => {}
+        }
+    }
+
+    writeln!(
+        out,
+        "\n{} parts in {wall:.3?} on {threads} thread{}, {slow} over the {budget:?} budget",
+        jobs.len(),
+        if threads == 1 { "" } else { "s" }
+    )?;
+    Ok(results.iter().all(Result::is_ok))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{thread, time::Duration};
+
+    use crate::all::pool;
+
+    #[test]
+    fn keeps_job_order() {
+        let jobs: Vec<u64> = (0..20).collect();
+        let squares = pool(&jobs, 4, |&n| {
+            // Later jobs finish first.
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(squares, jobs.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn no_jobs() {
+        assert_eq!(pool(&[] as &[u8], 8, |&n| n), Vec::<u8>::new());
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    error::Error,
+    io::{stdin, stdout, IsTerminal},
+    path::{Path, PathBuf},
+};
+
+use advent::{
+    animate::{self, Animate, Player},
+    output::Record,
+    repl::{repl, Explore},
+    validate::{self, Validate},
+    ParseError, Solution,
+};
+use calories::Elves;
+use cleanup::Assignments;
+use crt::CRT;
+use folder_size::FS;
+use rope::Rope;
+use rps::Match;
+use rucksack::Party;
+use simians::Simians;
+use start_of_packet::Datastream;
+use treetops::Forest;
+
+fn animate<S: Animate>(part: u8, input: &str) -> Result<(), Box<dyn Error>> {
+    let solution = S::parse(input).map_err(|e| e.report(input))?;
+    Player::new().run(&mut *solution.animate(part), &animate::controls())?;
+    Ok(())
+}
+
+fn explore<S: Explore>(input: &str) -> Result<(), Box<dyn Error>> {
+    let solution = S::parse(input).map_err(|e| e.report(input))?;
+    let prompt = stdin().is_terminal();
+    if prompt {
+        println!("type `help` for commands");
+    }
+    repl(&solution, stdin().lock(), stdout(), prompt)?;
+    Ok(())
+}
+
+type Solver = fn(u8, &[u8], &str) -> Result<Vec<Record>, ParseError>;
+type Animator = fn(u8, &str) -> Result<(), Box<dyn Error>>;
+type Explorer = fn(&str) -> Result<(), Box<dyn Error>>;
+type Validator = fn(&str) -> Vec<ParseError>;
+
+pub struct Day {
+    pub day: u8,
+    pub input: &'static str,
+    solver: Solver,
+    pub animate: Option<Animator>,
+    pub explore: Option<Explorer>,
+    validator: Validator,
+}
+
+impl Day {
+    const fn new<S: Solution>(day: u8, input: &'static str) -> Self {
+        Day {
+            day,
+            input,
+            solver: advent::solve::<S>,
+            animate: None,
+            explore: None,
+            validator: validate::parse_only::<S>,
+        }
+    }
+
+    const fn animated<S: Animate>(self) -> Self {
+        Day {
+            animate: Some(animate::<S>),
+            ..self
+        }
+    }
+
+    const fn explorable<S: Explore>(self) -> Self {
+        Day {
+            explore: Some(explore::<S>),
+            ..self
+        }
+    }
+
+    const fn validated<S: Validate>(self) -> Self {
+        Day {
+            validator: S::validate,
+            ..self
+        }
+    }
+
+    /// Parses `input` once and answers each of `parts`.
+    pub fn solve(&self, parts: &[u8], input: &str) -> Result<Vec<Record>, String> {
+        (self.solver)(self.day, parts, input).map_err(|e| e.report(input))
+    }
+
+    /// Every problem in `input`, or just the first for days without a grammar.
+    pub fn validate(&self, input: &str) -> Vec<ParseError> {
+        (self.validator)(input)
+    }
+
+    pub fn find(day: u8) -> Result<&'static Day, String> {
+        DAYS.iter()
+            .find(|d| d.day == day)
+            .ok_or(format!("day {day} has no solution"))
+    }
+
+    /// The day's crate directory, where its input files live.
+    pub fn dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("{:02}", self.day))
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<Elves>(1, calories::INPUT).validated::<Elves>(),
+    Day::new::<Match>(2, rps::INPUT)
+        .explorable::<Match>()
+        .validated::<Match>(),
+    Day::new::<Party>(3, rucksack::INPUT).validated::<Party>(),
+    Day::new::<Assignments>(4, cleanup::INPUT).validated::<Assignments>(),
+    Day::new::<Datastream>(6, start_of_packet::INPUT),
+    Day::new::<FS>(7, folder_size::INPUT)
+        .explorable::<FS>()
+        .validated::<FS>(),
+    Day::new::<Forest>(8, treetops::INPUT)
+        .explorable::<Forest>()
+        .validated::<Forest>(),
+    Day::new::<Rope>(9, rope::INPUT)
+        .animated::<Rope>()
+        .explorable::<Rope>()
+        .validated::<Rope>(),
+    Day::new::<CRT>(10, crt::INPUT)
+        .animated::<CRT>()
+        .explorable::<CRT>()
+        .validated::<CRT>(),
+    Day::new::<Simians>(11, simians::INPUT),
+];
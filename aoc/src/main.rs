@@ -0,0 +1,302 @@
+use std::{
+    error::Error,
+    io::stdout,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use advent::{input::Source, output::Format, span, validate};
+
+mod all;
+mod days;
+mod new_day;
+mod serve;
+mod verify;
+mod watch;
+
+use days::Day;
+
+const USAGE: &str = "usage: aoc --day N [--part 1|2] [--input PATH|-] [--format text|json|csv]
+       aoc --day N [--part 1|2] [--input PATH|-] --animate
+       aoc repl --day N [--input PATH]
+       aoc validate --day N [--input PATH|-]
+       aoc all [--threads N] [--budget MS] [--format text|json|csv]
+       aoc watch --day N [--part 1|2] [--input PATH] [--interval MS]
+       aoc serve [--port N]
+       aoc verify [--answers PATH]
+       aoc new-day N NAME
+
+Set AOC_LOG to a level, or module=level pairs, to see logging: AOC_LOG=info,rope=trace";
+
+enum Command {
+    Run {
+        day: u8,
+        parts: Vec<u8>,
+        input: Source,
+        format: Format,
+        animate: bool,
+    },
+    Repl {
+        day: u8,
+        input: Source,
+    },
+    Validate {
+        day: u8,
+        input: Source,
+    },
+    All {
+        threads: usize,
+        budget: Duration,
+        format: Format,
+    },
+    Watch {
+        day: u8,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+        interval: Duration,
+    },
+    Verify {
+        answers: PathBuf,
+    },
+    Serve {
+        port: u16,
+    },
+    NewDay {
+        day: u8,
+        name: String,
+    },
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut repl = false;
+        let mut validate = false;
+        let mut all = false;
+        let mut watch = false;
+        let mut serve = false;
+        let mut verify = false;
+        let mut new_day = None;
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut answers = None;
+        let mut format = Format::default();
+        let mut animate = false;
+        let mut threads = None;
+        let mut budget = Duration::from_millis(100);
+        let mut interval = Duration::from_millis(500);
+        let mut port = 8022;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+            match arg.as_str() {
+                "repl" if !repl => repl = true,
+                "validate" if !validate => validate = true,
+                "all" if !all => all = true,
+                "watch" if !watch => watch = true,
+                "serve" if !serve => serve = true,
+                "verify" if !verify => verify = true,
+                "new-day" if new_day.is_none() => new_day = Some(Vec::new()),
+                "--day" => day = Some(value()?.parse().map_err(|_| "--day must be a number")?),
+                "--part" => {
+                    part = match value()?.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err("--part must be 1 or 2".to_string()),
+                    }
+                }
+                "--format" => format = value()?.parse()?,
+                "--input" => input = Some(value()?),
+                "--answers" => answers = Some(value()?),
+                "--animate" => animate = true,
+                "--threads" => {
+                    threads = Some(
+                        value()?
+                            .parse()
+                            .ok()
+                            .filter(|&n| n > 0)
+                            .ok_or("--threads must be a positive number")?,
+                    )
+                }
+                "--budget" => {
+                    budget = Duration::from_millis(
+                        value()?
+                            .parse()
+                            .map_err(|_| "--budget must be a number of milliseconds")?,
+                    )
+                }
+                "--port" => {
+                    port = value()?
+                        .parse()
+                        .map_err(|_| "--port must be a port number")?
+                }
+                "--interval" => {
+                    interval = Duration::from_millis(
+                        value()?
+                            .parse()
+                            .map_err(|_| "--interval must be a number of milliseconds")?,
+                    )
+                }
+                _ => match new_day.as_mut() {
+                    Some(names) if !arg.starts_with('-') => names.push(arg),
+                    _ => return Err(format!("unexpected argument `{arg}`")),
+                },
+            }
+        }
+
+        if let Some(names) = new_day {
+            let [day, name] = <[String; 2]>::try_from(names)
+                .map_err(|_| "new-day takes a day number and a crate name")?;
+            return Ok(Command::NewDay {
+                day: day.parse().map_err(|_| "day must be a number")?,
+                name,
+            });
+        }
+
+        if repl {
+            if input.as_deref() == Some("-") {
+                return Err("repl reads commands from stdin, pass the input as a file".to_string());
+            }
+            return Ok(Command::Repl {
+                day: day.ok_or("--day is required")?,
+                input: Source::from_arg(input.as_deref()),
+            });
+        }
+
+        if validate {
+            return Ok(Command::Validate {
+                day: day.ok_or("--day is required")?,
+                input: Source::from_arg(input.as_deref()),
+            });
+        }
+
+        if all {
+            let threads = match threads {
+                Some(threads) => threads,
+                None => thread::available_parallelism().map_or(1, usize::from),
+            };
+            return Ok(Command::All {
+                threads,
+                budget,
+                format,
+            });
+        }
+
+        if watch {
+            if input.as_deref() == Some("-") {
+                return Err("watch cannot read stdin".to_string());
+            }
+            return Ok(Command::Watch {
+                day: day.ok_or("--day is required")?,
+                part,
+                input: input.map(PathBuf::from),
+                interval,
+            });
+        }
+
+        if serve {
+            return Ok(Command::Serve { port });
+        }
+
+        if verify {
+            return Ok(Command::Verify {
+                answers: answers.map_or_else(verify::answers_path, PathBuf::from),
+            });
+        }
+
+        Ok(Command::Run {
+            day: day.ok_or("--day is required")?,
+            parts: part.map_or(vec![1, 2], |part| vec![part]),
+            input: Source::from_arg(input.as_deref()),
+            format,
+            animate,
+        })
+    }
+}
+
+fn run() -> Result<bool, Box<dyn Error>> {
+    let command = Command::parse(std::env::args().skip(1)).map_err(|e| format!("{e}\n{USAGE}"))?;
+
+    match command {
+        Command::Run {
+            day,
+            parts,
+            input,
+            format,
+            animate,
+        } => {
+            let day = Day::find(day)?;
+            let _day = span!("day {}", day.day);
+            let input = input.read(day.input)?;
+
+            if animate {
+                let animate = day
+                    .animate
+                    .ok_or(format!("day {} has no animation", day.day))?;
+                animate(parts[0], &input)?;
+                return Ok(true);
+            }
+
+            let records = day.solve(&parts, &input)?;
+            let mut out = stdout().lock();
+            format.header(&mut out)?;
+            for record in records.iter() {
+                format.write(record, &mut out)?;
+            }
+            Ok(true)
+        }
+        Command::Repl { day, input } => {
+            let day = Day::find(day)?;
+            let explore = day
+                .explore
+                .ok_or(format!("day {} cannot be explored", day.day))?;
+            explore(&input.read(day.input)?)?;
+            Ok(true)
+        }
+        Command::Validate { day, input } => {
+            let day = Day::find(day)?;
+            let input = input.read(day.input)?;
+            let errors = day.validate(&input);
+            println!("{}", validate::report(&errors, &input));
+            Ok(errors.is_empty())
+        }
+        Command::All {
+            threads,
+            budget,
+            format,
+        } => all::run_all(threads, budget, format),
+        Command::Watch {
+            day,
+            part,
+            input,
+            interval,
+        } => {
+            // The day runs from its own directory.
+            let input = input.map(|path| path.canonicalize().unwrap_or(path));
+            let input = input.as_deref().map(Path::to_string_lossy);
+            watch::watch(Day::find(day)?, part, input.as_deref(), interval)
+        }
+        Command::Serve { port } => serve::serve(port),
+        Command::Verify { answers } => verify::verify(&answers),
+        Command::NewDay { day, name } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .canonicalize()?;
+            new_day::new_day(&root, day, &name)?;
+            Ok(true)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use std::{error::Error, fs, path::Path};
+
+const MANIFEST: &str = r#"[package]
+name = "$NAME"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+advent = { path = "../advent" }
+
+[[bench]]
+name = "$NAME"
+harness = false
+"#;
+
+const LIB: &str = r#"use advent::{parse, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("../data");
+
+/// Day $DAY, not modelled yet: for now, the input's lines.
+pub struct $TYPE(Vec<String>);
+
+impl Solution for $TYPE {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse::lines(input, |line| Ok(line.to_string())).map($TYPE)
+    }
+
+    fn part_one(&self) -> usize {
+        self.0.len()
+    }
+
+    fn part_two(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::Solution;
+
+    use crate::$TYPE;
+
+    // The example from the puzzle text.
+    const TEST_DATA: &str = "";
+
+    #[test]
+    fn part_1() {
+        let $VAR = $TYPE::parse(TEST_DATA).unwrap();
+        assert_eq!($VAR.part_one(), 0);
+    }
+
+    #[test]
+    fn part_2() {
+        let $VAR = $TYPE::parse(TEST_DATA).unwrap();
+        assert_eq!($VAR.part_two(), 0);
+    }
+}
+"#;
+
+const MAIN: &str = r#"use std::process::ExitCode;
+
+use $VAR::{$TYPE, INPUT};
+
+fn main() -> ExitCode {
+    advent::run::<$TYPE>($DAY, INPUT)
+}
+"#;
+
+const BENCH: &str = r#"use advent::{bench::Bencher, input::normalize, Solution};
+use $VAR::{$TYPE, INPUT};
+
+fn main() {
+    let input = normalize(INPUT);
+    let mut bencher = Bencher::from_args();
+
+    bencher.bench("$TYPE::parse", || $TYPE::parse(&input).unwrap());
+
+    let $VAR = $TYPE::parse(&input).unwrap();
+    bencher.bench("$TYPE::part_one", || $VAR.part_one());
+    bencher.bench("$TYPE::part_two", || $VAR.part_two());
+}
+"#;
+
+/// What `cargo new` leaves in `src/main.rs`, which is safe to replace.
+const HELLO: &str = "fn main() {\n    println!(\"Hello, world!\");\n}\n";
+
+/// Names derived from a day's crate name, like `folder-size`.
+struct Names {
+    day: u8,
+    name: String,
+    /// The crate as a Rust path: `folder_size`.
+    var: String,
+    /// The solution type: `FolderSize`.
+    ty: String,
+}
+
+impl Names {
+    fn new(day: u8, name: &str) -> Result<Self, String> {
+        let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !(1..=25).contains(&day) {
+            return Err(format!("day must be between 1 and 25, got {day}"));
+        }
+        if !valid {
+            return Err(format!(
+                "crate name must be lowercase letters, digits and `-`, got `{name}`"
+            ));
+        }
+
+        let ty = name
+            .split('-')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map_or(String::new(), |first| {
+                    first.to_ascii_uppercase().to_string() + chars.as_str()
+                })
+            })
+            .collect();
+        Ok(Names {
+            day,
+            name: name.to_string(),
+            var: name.replace('-', "_"),
+            ty,
+        })
+    }
+
+    fn dir(&self) -> String {
+        format!("{:02}", self.day)
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("$NAME", &self.name)
+            .replace("$VAR", &self.var)
+            .replace("$TYPE", &self.ty)
+            .replace("$DAY", &self.day.to_string())
+    }
+}
+
+/// Creates the crate for `day` under `root` and wires it into the workspace
+/// and the runner. `verify` lists the new day as unverified until its
+/// answers are added to the answers file.
+///
+/// A directory left by `cargo new` is filled in; one that already has a
+/// `src/lib.rs` is refused. Other files already there, like `data`, are kept.
+pub fn new_day(root: &Path, day: u8, name: &str) -> Result<(), Box<dyn Error>> {
+    let names = Names::new(day, name)?;
+    let dir = root.join(names.dir());
+    if dir.join("src/lib.rs").exists() {
+        return Err(format!("{} already has a solution", dir.display()).into());
+    }
+    let main = dir.join("src/main.rs");
+    if main.exists() && fs::read_to_string(&main)? != HELLO {
+        return Err(format!("{} has been edited, not replacing it", main.display()).into());
+    }
+
+    // Check every edit applies before writing anything.
+    let workspace = add_member(&read(&root.join("Cargo.toml"))?, &names)?;
+    let runner = add_dependency(&read(&root.join("aoc/Cargo.toml"))?, &names)?;
+    let days = add_day(&read(&root.join("aoc/src/days.rs"))?, &names)?;
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("benches"))?;
+    fs::write(dir.join("Cargo.toml"), names.fill(MANIFEST))?;
+    fs::write(dir.join("src/lib.rs"), names.fill(LIB))?;
+    fs::write(&main, names.fill(MAIN))?;
+    fs::write(
+        dir.join("benches").join(format!("{}.rs", names.name)),
+        names.fill(BENCH),
+    )?;
+    if !dir.join("data").exists() {
+        fs::write(dir.join("data"), "")?;
+    }
+
+    fs::write(root.join("Cargo.toml"), workspace)?;
+    fs::write(root.join("aoc/Cargo.toml"), runner)?;
+    fs::write(root.join("aoc/src/days.rs"), days)?;
+
+    println!("created {} as `{}`", dir.display(), names.name);
+    println!("put the puzzle input in {}", dir.join("data").display());
+    println!(
+        "once solved, add its answers to {}",
+        root.join("answers").display()
+    );
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Adds the day's directory to the workspace `members`, keeping them sorted.
+fn add_member(manifest: &str, names: &Names) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("no workspace members in Cargo.toml")?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unclosed workspace members in Cargo.toml")?;
+
+    let dir = format!("\"{}\"", names.dir());
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&dir.as_str()) {
+        return Err(format!("{} is already a workspace member", names.dir()));
+    }
+    members.push(&dir);
+    members.sort();
+
+    Ok(format!(
+        "{}\n  {}\n{}",
+        &manifest[..start],
+        members.join(",\n  "),
+        &manifest[end..]
+    ))
+}
+
+/// Adds the day's crate to the runner's dependencies, after the last day.
+fn add_dependency(manifest: &str, names: &Names) -> Result<String, String> {
+    if manifest
+        .lines()
+        .any(|l| l.starts_with(&format!("{} = ", names.name)))
+    {
+        return Err(format!("aoc already depends on {}", names.name));
+    }
+    let line = format!("{} = {{ path = \"../{}\" }}", names.name, names.dir());
+
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let after = lines
+        .iter()
+        .rposition(|l| l.contains("path = \"../") && !l.starts_with("advent "))
+        .ok_or("no day dependencies in aoc/Cargo.toml")?;
+    lines.insert(after + 1, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Imports the day's solution in `days.rs` and adds it to `DAYS`.
+fn add_day(days: &str, names: &Names) -> Result<String, String> {
+    let import = format!("use {}::{};", names.var, names.ty);
+    let entry = format!(
+        "    Day::new::<{}>({}, {}::INPUT),",
+        names.ty, names.day, names.var
+    );
+    if days.contains(&format!("Day::new::<{}>(", names.ty)) {
+        return Err(format!("DAYS already has {}", names.ty));
+    }
+
+    let mut lines: Vec<&str> = days.lines().collect();
+    // The one-line `use day::Solution;` imports are kept sorted.
+    let imports = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with("use ") && l.ends_with(';'))
+        .map(|(i, _)| i);
+    let at = imports
+        .clone()
+        .find(|&i| lines[i] > import.as_str())
+        .or_else(|| imports.max().map(|i| i + 1))
+        .ok_or("no day imports in days.rs")?;
+    lines.insert(at, &import);
+
+    let table = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or("no DAYS table in days.rs")?;
+    let close = table
+        + lines[table..]
+            .iter()
+            .position(|l| *l == "];")
+            .ok_or("unclosed DAYS table in days.rs")?;
+    lines.insert(close, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::new_day::{add_day, add_dependency, add_member, Names};
+
+    #[test]
+    fn names() {
+        let names = Names::new(7, "folder-size").unwrap();
+        assert_eq!(
+            (names.var.as_str(), names.ty.as_str()),
+            ("folder_size", "FolderSize")
+        );
+        assert_eq!(names.dir(), "07");
+
+        assert!(Names::new(26, "late").is_err());
+        assert!(Names::new(11, "Simians").is_err());
+        assert!(Names::new(11, "9lives").is_err());
+    }
+
+    #[test]
+    fn registers_member() {
+        let names = Names::new(11, "simians").unwrap();
+        let manifest =
+            "[workspace]\nmembers = [\n  \"10\",\n  \"advent\"\n]\nexclude = [\"fuzz\"]\n";
+        let manifest = add_member(manifest, &names).unwrap();
+        assert_eq!(
+            manifest,
+            "[workspace]\nmembers = [\n  \"10\",\n  \"11\",\n  \"advent\"\n]\nexclude = [\"fuzz\"]\n"
+        );
+        assert!(add_member(&manifest, &names).is_err());
+    }
+
+    #[test]
+    fn registers_dependency() {
+        let names = Names::new(11, "simians").unwrap();
+        let manifest =
+            "[dependencies]\nadvent = { path = \"../advent\" }\ncrt = { path = \"../10\" }\n";
+        assert_eq!(
+            add_dependency(manifest, &names).unwrap(),
+            "[dependencies]\nadvent = { path = \"../advent\" }\ncrt = { path = \"../10\" }\nsimians = { path = \"../11\" }\n"
+        );
+    }
+
+    #[test]
+    fn registers_day() {
+        let names = Names::new(11, "simians").unwrap();
+        let days = "\
+use advent::Solution;
+use crt::CRT;
+use treetops::Forest;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<CRT>(10, crt::INPUT),
+];
+";
+        let days = add_day(days, &names).unwrap();
+        assert_eq!(
+            days,
+            "\
+use advent::Solution;
+use crt::CRT;
+use simians::Simians;
+use treetops::Forest;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<CRT>(10, crt::INPUT),
+    Day::new::<Simians>(11, simians::INPUT),
+];
+"
+        );
+        assert!(add_day(&days, &names).is_err());
+    }
+}
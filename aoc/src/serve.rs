@@ -0,0 +1,283 @@
+use std::{
+    error::Error,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use advent::{input::normalize, output::json_string, Solution};
+use crt::CRT;
+use folder_size::{Entry, FS};
+use rope::Rope;
+use treetops::Forest;
+
+const PAGE: &str = include_str!("serve.html");
+
+/// How long a connection may wait between lines of its request, so one that
+/// never sends anything, like a browser's preconnect, is dropped.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A fixed response, built once when the server starts.
+pub struct Page {
+    pub path: &'static str,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+/// The HTML page and the JSON it draws, from each day's embedded input.
+pub fn pages() -> Result<Vec<Page>, Box<dyn Error>> {
+    let json = "application/json";
+    Ok(vec![
+        Page {
+            path: "/",
+            content_type: "text/html; charset=utf-8",
+            body: PAGE.to_string(),
+        },
+        Page {
+            path: "/api/07",
+            content_type: json,
+            body: tree(&FS::parse(&normalize(folder_size::INPUT))?),
+        },
+        Page {
+            path: "/api/08",
+            content_type: json,
+            body: forest(&Forest::parse(&normalize(treetops::INPUT))?),
+        },
+        Page {
+            path: "/api/09",
+            content_type: json,
+            body: rope(&<Rope as Solution>::parse(&normalize(rope::INPUT))?),
+        },
+        Page {
+            path: "/api/10",
+            content_type: json,
+            body: screen(&<CRT as Solution>::parse(&normalize(crt::INPUT))?),
+        },
+    ])
+}
+
+/// `{"name", "size", "children"}` for each directory, and no children for files.
+fn tree(fs: &FS) -> String {
+    fn node(name: &str, entry: &Entry) -> String {
+        let children = match entry {
+            Entry::Dir(dir) => {
+                let children: Vec<String> = dir
+                    .entries()
+                    .map(|(name, child)| node(name, child))
+                    .collect();
+                format!(r#","children":[{}]"#, children.join(","))
+            }
+            Entry::File(_) => String::new(),
+        };
+        format!(
+            r#"{{"name":{},"size":{}{children}}}"#,
+            json_string(name),
+            entry.size()
+        )
+    }
+    node("/", fs.root())
+}
+
+/// Heights with each tree's visibility and scenic score, row by row.
+fn forest(forest: &Forest) -> String {
+    let grid = forest.heights();
+    let rows = |cell: &dyn Fn(usize, usize) -> String| {
+        let rows: Vec<String> = (0..grid.height())
+            .map(|row| {
+                let cells: Vec<String> = (0..grid.width()).map(|col| cell(row, col)).collect();
+                format!("[{}]", cells.join(","))
+            })
+            .collect();
+        format!("[{}]", rows.join(","))
+    };
+    format!(
+        r#"{{"heights":{},"visible":{},"scenic":{}}}"#,
+        rows(&|row, col| grid.row(row)[col].to_string()),
+        rows(&|row, col| forest.visible(row, col).to_string()),
+        rows(&|row, col| forest.scenic(row, col).to_string()),
+    )
+}
+
+/// The tail's position after every step, for the short and the long rope.
+fn rope(rope: &Rope) -> String {
+    let trails: Vec<String> = [2, 10]
+        .iter()
+        .map(|&knots| {
+            let points: Vec<String> = rope
+                .trail(knots)
+                .iter()
+                .map(|p| format!("[{},{}]", p.row, p.col))
+                .collect();
+            format!(r#"{{"knots":{knots},"trail":[{}]}}"#, points.join(","))
+        })
+        .collect();
+    format!(r#"{{"trails":[{}]}}"#, trails.join(","))
+}
+
+fn screen(crt: &CRT) -> String {
+    let image = crt.part_two().0;
+    let rows: Vec<String> = image
+        .lines()
+        .filter(|row| !row.is_empty())
+        .map(json_string)
+        .collect();
+    format!(
+        r#"{{"signal":{},"rows":[{}]}}"#,
+        crt.part_one(),
+        rows.join(",")
+    )
+}
+
+/// The status line and page for a request line like `GET /api/08 HTTP/1.1`.
+pub fn route<'a>(request: &str, pages: &'a [Page]) -> (&'static str, Option<&'a Page>) {
+    let mut words = request.split_whitespace();
+    let (Some(method), Some(target)) = (words.next(), words.next()) else {
+        return ("400 Bad Request", None);
+    };
+    if method != "GET" {
+        return ("405 Method Not Allowed", None);
+    }
+    let path = target.split('?').next().unwrap_or(target);
+    match pages.iter().find(|page| page.path == path) {
+        Some(page) => ("200 OK", Some(page)),
+        None => ("404 Not Found", None),
+    }
+}
+
+fn respond(stream: TcpStream, pages: &[Page]) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    // The headers are read but not needed.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let (status, page) = route(&request, pages);
+    let (content_type, body) = page.map_or(("text/plain", status), |page| {
+        (page.content_type, page.body.as_str())
+    });
+    let mut out = &stream;
+    write!(
+        out,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    out.flush()
+}
+
+/// Answers each connection to `listener` on its own thread, so a slow one
+/// does not hold up the rest.
+fn accept(listener: TcpListener, pages: Arc<Vec<Page>>) {
+    for stream in listener.incoming() {
+        let pages = Arc::clone(&pages);
+        let result = stream.and_then(|stream| {
+            thread::Builder::new().spawn(move || {
+                if let Err(e) = respond(stream, &pages) {
+                    advent::warn!("request failed: {e}");
+                }
+            })
+        });
+        if let Err(e) = result {
+            advent::warn!("request failed: {e}");
+        }
+    }
+}
+
+/// Serves the visualizations on localhost until killed.
+pub fn serve(port: u16) -> Result<bool, Box<dyn Error>> {
+    let pages = Arc::new(pages()?);
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!(
+        "serving on http://{}, ctrl-c to stop",
+        listener.local_addr()?
+    );
+
+    accept(listener, pages);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::Arc,
+        thread,
+    };
+
+    use folder_size::FS;
+
+    use crate::serve::{accept, pages, respond, route, tree, Page};
+
+    #[test]
+    fn routes() {
+        let pages = [Page {
+            path: "/api/10",
+            content_type: "application/json",
+            body: "{}".to_string(),
+        }];
+        assert_eq!(route("GET /api/10 HTTP/1.1\r\n", &pages).0, "200 OK");
+        assert_eq!(route("GET /api/10?x=1 HTTP/1.1\r\n", &pages).0, "200 OK");
+        assert_eq!(route("GET /api/11 HTTP/1.1\r\n", &pages).0, "404 Not Found");
+        assert_eq!(
+            route("POST /api/10 HTTP/1.1\r\n", &pages).0,
+            "405 Method Not Allowed"
+        );
+        assert_eq!(route("\r\n", &pages).0, "400 Bad Request");
+    }
+
+    #[test]
+    fn trees() {
+        let fs = FS::parse("$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 \"c\"").unwrap();
+        assert_eq!(
+            tree(&fs),
+            r#"{"name":"/","size":15,"children":[{"name":"a","size":5,"children":[{"name":"\"c\"","size":5}]},{"name":"b","size":10}]}"#
+        );
+    }
+
+    #[test]
+    fn serves() {
+        let pages = pages().unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            respond(stream, &pages).unwrap();
+        });
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        client
+            .write_all(b"GET /api/10 HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with(r####""signal":15220,"rows":["###  #### #### #### #  # ###  ####  ##  ","#  # #       # #    # #  #  # #    #  # ","#  # ###    #  ###  ##   ###  ###  #  # ","###  #     #   #    # #  #  # #    #### ","# #  #    #    #    # #  #  # #    #  # ","#  # #    #### #### #  # ###  #    #  # "]}"####));
+    }
+
+    #[test]
+    fn idle_connections_do_not_block() {
+        let pages = Arc::new(vec![Page {
+            path: "/",
+            content_type: "text/plain",
+            body: "hi".to_string(),
+        }]);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || accept(listener, pages));
+
+        let _idle = TcpStream::connect(addr).unwrap();
+        let mut client = TcpStream::connect(addr).unwrap();
+        client.write_all(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        assert!(response.ends_with("\r\n\r\nhi"), "{response}");
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use advent::{input::Source, output::Record, span, ParseError};
+
+use crate::days::{Day, DAYS};
+
+/// The checked-in answers file at the workspace root.
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers")
+}
+
+/// One line of the answers file: `day part input answer`.
+///
+/// Newlines in the answer are written as `\n`, so rendered screens fit on one line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+impl TryFrom<&str> for Expected {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut fields = value.splitn(4, ' ');
+        let mut column = 0;
+        let mut field = |expected: &str| {
+            let field = fields
+                .next()
+                .ok_or_else(|| ParseError::new(value, expected).shift(column))?;
+            let at = column;
+            column += field.len() + 1;
+            Ok::<_, ParseError>((field, at))
+        };
+
+        let (day, at) = field("a day number")?;
+        let day = day
+            .parse()
+            .map_err(|_| ParseError::new(day, "a day number").shift(at))?;
+        let (part, at) = field("a part `1` or `2`")?;
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(ParseError::new(part, "a part `1` or `2`").shift(at)),
+        };
+        let (input, _) = field("an input file name")?;
+        let (answer, _) = field("an answer")?;
+
+        Ok(Expected {
+            day,
+            part,
+            input: input.to_string(),
+            answer: unescape(answer),
+        })
+    }
+}
+
+/// Reads an answers file, skipping blank lines and `#` comments.
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, ParseError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| Expected::try_from(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Each day and part in `days` with no entry in `expected`, such as a day
+/// just made by `new-day` whose answers are not known yet.
+fn unverified(days: &[Day], expected: &[Expected]) -> Vec<(u8, u8)> {
+    days.iter()
+        .flat_map(|day| [(day.day, 1), (day.day, 2)])
+        .filter(|&(day, part)| !expected.iter().any(|e| (e.day, e.part) == (day, part)))
+        .collect()
+}
+
+/// Runs every entry in the answers file, returning whether they all passed.
+///
+/// Parts of a day with no entry are listed as unverified, but do not fail.
+pub fn verify(path: &Path) -> Result<bool, Box<dyn Error>> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let expected = parse_answers(&text).map_err(|e| e.report(&text))?;
+
+    let mut failed = 0;
+    println!(
+        "{:>3} {:>4} {:<12} {:<6} {:>11}",
+        "day", "part", "input", "result", "time"
+    );
+    for entry in expected.iter() {
+        let day = Day::find(entry.day)?;
+        let _day = span!("day {}", entry.day);
+        let input = Source::File(day.dir().join(&entry.input)).read(day.input)?;
+
+        let result = day
+            .solve(&[entry.part], &input)
+            .map(|mut records| records.remove(0));
+        let elapsed = result
+            .as_ref()
+            .map_or(Duration::ZERO, |record| record.parse + record.solve);
+
+        let (status, detail) = match result {
+            Ok(Record { answer, .. }) if answer.to_string() == entry.answer => ("pass", None),
+            Ok(Record { answer, .. }) => (
+                "FAIL",
+                Some(format!(
+                    "expected `{}`, got `{}`",
+                    escape(&entry.answer),
+                    escape(&answer.to_string())
+                )),
+            ),
+            Err(e) => ("ERROR", Some(e.to_string())),
+        };
+        if detail.is_some() {
+            failed += 1;
+        }
+
+        println!(
+            "{:>3} {:>4} {:<12} {:<6} {:>11.3?}",
+            entry.day, entry.part, entry.input, status, elapsed
+        );
+        if let Some(detail) = detail {
+            println!("    {detail}");
+        }
+    }
+    let unverified = unverified(DAYS, &expected);
+    for (day, part) in unverified.iter() {
+        println!("{day:>3} {part:>4} {:<12} unverified", "-");
+    }
+    print!("{} passed, {} failed", expected.len() - failed, failed);
+    match unverified.len() {
+        0 => println!(),
+        n => println!(", {n} unverified"),
+    }
+
+    Ok(failed == 0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        days::DAYS,
+        verify::{escape, parse_answers, unverified, Expected},
+    };
+
+    #[test]
+    fn parses_answers() {
+        let answers =
+            parse_answers("# day part input answer\n08 1 data 21\n\n10 2 small_data ##..\\n#..#\n")
+                .unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                Expected {
+                    day: 8,
+                    part: 1,
+                    input: "data".to_string(),
+                    answer: "21".to_string()
+                },
+                Expected {
+                    day: 10,
+                    part: 2,
+                    input: "small_data".to_string(),
+                    answer: "##..\n#..#".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        let screen = "#  #\n \\ \n";
+        let answers = parse_answers(&format!("10 2 data {}", escape(screen))).unwrap();
+        assert_eq!(answers[0].answer, screen);
+    }
+
+    #[test]
+    fn bad_answers() {
+        let error = parse_answers("08 1 data 21\n08 3 data 8").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = parse_answers("08 1 data").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 11));
+    }
+
+    #[test]
+    fn finds_unverified() {
+        let answers = parse_answers("01 1 data 70116\n01 2 small_data 45000").unwrap();
+        let missing = unverified(DAYS, &answers);
+        assert!(!missing.contains(&(1, 1)) && !missing.contains(&(1, 2)));
+        assert!(missing.contains(&(2, 1)) && missing.contains(&(11, 2)));
+        assert_eq!(missing.len(), DAYS.len() * 2 - 2);
+    }
+}
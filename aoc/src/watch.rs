@@ -0,0 +1,175 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use advent::snapshot::diff;
+
+use crate::days::Day;
+
+/// Modification times of every file in `dir`, skipping build output and
+/// hidden files.
+fn snapshot(dir: &Path) -> Vec<(PathBuf, SystemTime)> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            if name.to_string_lossy().starts_with('.') || name == "target" {
+                continue;
+            }
+            let Ok(meta) = entry.metadata() else { continue };
+            if meta.is_dir() {
+                pending.push(path);
+            } else if let Ok(modified) = meta.modified() {
+                files.push((path, modified));
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// The files that differ between two snapshots, including added and removed ones.
+fn changed(old: &[(PathBuf, SystemTime)], new: &[(PathBuf, SystemTime)]) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = new
+        .iter()
+        .filter(|file| !old.contains(file))
+        .chain(
+            old.iter()
+                .filter(|(path, _)| !new.iter().any(|(p, _)| p == path)),
+        )
+        .map(|(path, _)| path.clone())
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// The day's package name, from its manifest.
+fn package(dir: &Path) -> Result<String, String> {
+    let manifest = dir.join("Cargo.toml");
+    let text = fs::read_to_string(&manifest).map_err(|e| format!("{}: {e}", manifest.display()))?;
+    text.lines()
+        .find_map(|line| line.strip_prefix("name = "))
+        .map(|name| name.trim_matches('"').to_string())
+        .ok_or(format!("{} has no package name", manifest.display()))
+}
+
+/// Rebuilds and runs the day's binary and its tests whenever a file in its
+/// directory changes, showing how the answer moved since the last run.
+///
+/// Both go through cargo, so edits to the solution are picked up too.
+pub fn watch(
+    day: &Day,
+    part: Option<u8>,
+    input: Option<&str>,
+    interval: Duration,
+) -> Result<bool, Box<dyn Error>> {
+    let dir = day.dir().canonicalize()?;
+    let package = package(&dir)?;
+
+    let mut args = vec!["run", "-q", "-p", &package, "--"];
+    if let Some(input) = input {
+        args.push(input);
+    }
+    let part = part.map(|part| part.to_string());
+    if let Some(part) = part.as_deref() {
+        args.extend(["--part", part]);
+    }
+
+    let mut last: Option<String> = None;
+    let mut files = snapshot(&dir);
+    let mut changes: Vec<PathBuf> = Vec::new();
+    loop {
+        println!("\x1b[2J\x1b[H== day {} ({package})", day.day);
+        for path in changes.iter() {
+            println!("changed: {}", path_in(&dir, path));
+        }
+
+        let run = Command::new("cargo")
+            .args(&args)
+            .current_dir(&dir)
+            .output()?;
+        let answer = String::from_utf8_lossy(&run.stdout).into_owned();
+        if run.status.success() {
+            match last.as_deref() {
+                Some(previous) if previous != answer => {
+                    print!("answer changed:\n{}", diff(previous, &answer))
+                }
+                Some(_) => print!("answer unchanged:\n{answer}"),
+                None => print!("answer:\n{answer}"),
+            }
+            last = Some(answer);
+        } else {
+            print!("run failed:\n{}", String::from_utf8_lossy(&run.stderr));
+        }
+
+        let tests = Command::new("cargo")
+            .args(["test", "-q", "-p", &package, "--lib"])
+            .current_dir(&dir)
+            .output()?;
+        if tests.status.success() {
+            println!("\nexample tests passed");
+        } else {
+            print!(
+                "\nexample tests failed:\n{}{}",
+                String::from_utf8_lossy(&tests.stdout),
+                String::from_utf8_lossy(&tests.stderr)
+            );
+        }
+        println!("\nwatching {} for changes, ctrl-c to stop", dir.display());
+
+        loop {
+            thread::sleep(interval);
+            let now = snapshot(&dir);
+            changes = changed(&files, &now);
+            files = now;
+            if !changes.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+fn path_in(dir: &Path, path: &Path) -> String {
+    path.strip_prefix(dir).unwrap_or(path).display().to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use crate::watch::changed;
+
+    #[test]
+    fn finds_changes() {
+        let at = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        let old = vec![
+            (PathBuf::from("data"), at(1)),
+            (PathBuf::from("small_data"), at(1)),
+            (PathBuf::from("src/lib.rs"), at(1)),
+        ];
+        let new = vec![
+            (PathBuf::from("data"), at(2)),
+            (PathBuf::from("src/lib.rs"), at(1)),
+            (PathBuf::from("src/main.rs"), at(2)),
+        ];
+        assert_eq!(
+            changed(&old, &new),
+            ["data", "small_data", "src/main.rs"].map(PathBuf::from)
+        );
+        assert!(changed(&new, &new).is_empty());
+    }
+}
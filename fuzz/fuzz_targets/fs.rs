@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check_error;
+use folder_size::FS;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| check_error(text, FS::parse(text)));
@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent::Solution;
+use aoc_fuzz::check_error;
+use libfuzzer_sys::fuzz_target;
+use rope::Rope;
+
+fuzz_target!(|text: &str| check_error(text, Rope::parse(text)));
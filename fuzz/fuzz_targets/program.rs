@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent::Solution;
+use aoc_fuzz::check_error;
+use crt::CRT;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| check_error(text, CRT::parse(text)));
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check_error;
+use cleanup::parse_range_pairs;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| check_error(text, parse_range_pairs(text)));
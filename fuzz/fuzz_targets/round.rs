@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check_error;
+use libfuzzer_sys::fuzz_target;
+use rps::Round;
+
+fuzz_target!(|text: &str| check_error(text, Round::try_from(text)));
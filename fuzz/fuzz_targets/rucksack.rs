@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check_error;
+use libfuzzer_sys::fuzz_target;
+use rucksack::Rucksack;
+
+fuzz_target!(|text: &str| check_error(text, Rucksack::parse(text)));
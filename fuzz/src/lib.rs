@@ -0,0 +1,13 @@
+//! The check every fuzz target makes on the parser it drives.
+
+use advent::ParseError;
+
+/// Parsers must reject bad text with an error that points into it, never
+/// panic: fails if `result` is an error without a line and column, or one
+/// that cannot be shown against `text`.
+pub fn check_error<T>(text: &str, result: Result<T, ParseError>) {
+    if let Err(error) = result {
+        assert!(error.line >= 1 && error.column >= 1);
+        error.report(text);
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+mod direction;
+mod sparse;
+
+pub use direction::Direction;
+pub use sparse::{Point, SparseGrid};
+
+/// A cell in a [`Grid`], counted from the top left.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+}
+
+/// Rectangular storage, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A row whose length differs from the rows before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRow {
+    pub row: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, RaggedRow> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (row, line) in rows.into_iter().enumerate() {
+            let expected = *width.get_or_insert(line.len());
+            if line.len() != expected {
+                return Err(RaggedRow {
+                    row,
+                    width: line.len(),
+                    expected,
+                });
+            }
+            cells.extend(line);
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |row| &self[Pos::new(row, col)])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    /// One step from `pos`, if that stays on the grid.
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        let (drow, dcol) = dir.delta();
+        let row = pos.row.checked_add_signed(drow as isize)?;
+        let col = pos.col.checked_add_signed(dcol as isize)?;
+        let next = Pos::new(row, col);
+        self.contains(next).then_some(next)
+    }
+
+    /// The cells next to `pos` in each of `dirs`, skipping any off the edge.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: Pos,
+        dirs: &'a [Direction],
+    ) -> impl Iterator<Item = (Pos, &'a T)> + 'a {
+        dirs.iter()
+            .filter_map(move |dir| self.step(pos, *dir))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// The cells from `pos` to the edge in `dir`, not including `pos`.
+    pub fn ray(&self, pos: Pos, dir: Direction) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.step(pos, dir), move |p| self.step(*p, dir))
+            .map(|next| (next, &self[next]))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(Pos, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.positions().map(|pos| f(pos, &self[pos])).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).expect("position inside the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos).expect("position inside the grid")
+    }
+}
+
+/// Each row on its own line, with no trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use advent::assert_snapshot;
+
+    use crate::{Direction, Grid, Pos, RaggedRow};
+
+    fn example() -> Grid<u8> {
+        Grid::from_rows(vec![vec![3, 0, 3, 7], vec![2, 5, 5, 1], vec![6, 5, 3, 3]]).unwrap()
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.row(1), &[2, 5, 5, 1]);
+        assert_eq!(grid.col(3).copied().collect::<Vec<_>>(), vec![7, 1, 3]);
+        assert_eq!(grid.positions().count(), 12);
+        assert_eq!(grid[Pos::new(2, 0)], 6);
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+    }
+
+    #[test]
+    fn ragged() {
+        let grid = Grid::from_rows(vec![vec!['#', '.'], vec!['.']]);
+        assert_eq!(
+            grid,
+            Err(RaggedRow {
+                row: 1,
+                width: 1,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = example();
+        let around: Vec<u8> = grid
+            .neighbours(Pos::new(0, 0), &Direction::ORTHOGONAL)
+            .map(|(_, h)| *h)
+            .collect();
+        assert_eq!(around, vec![2, 0]);
+
+        let around = grid.neighbours(Pos::new(1, 1), &Direction::ALL).count();
+        assert_eq!(around, 8);
+    }
+
+    #[test]
+    fn renders_rays() {
+        let mut grid = Grid::new(11, 7, '.');
+        let centre = Pos::new(3, 5);
+        let hits: Vec<(Pos, char)> = Direction::ALL
+            .iter()
+            .zip("12345678".chars())
+            .flat_map(|(dir, c)| grid.ray(centre, *dir).map(move |(p, _)| (p, c)))
+            .collect();
+        for (p, c) in hits {
+            grid[p] = c;
+        }
+        grid[centre] = '*';
+        assert_snapshot!("rays", grid);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = example();
+        let left: Vec<u8> = grid
+            .ray(Pos::new(1, 3), Direction::Left)
+            .map(|(_, h)| *h)
+            .collect();
+        assert_eq!(left, vec![5, 5, 2]);
+
+        let diagonal: Vec<Pos> = grid
+            .ray(Pos::new(0, 0), Direction::DownRight)
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(diagonal, vec![Pos::new(1, 1), Pos::new(2, 2)]);
+
+        assert_eq!(grid.ray(Pos::new(0, 2), Direction::Up).count(), 0);
+    }
+
+    #[test]
+    fn renders() {
+        let grid = example().map(|_, h| if *h >= 5 { '#' } else { '.' });
+        assert_eq!(grid.to_string(), "...#\n.##.\n##..");
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::{Direction, Grid, Pos};
+
+/// A signed coordinate, for grids that grow in every direction from an origin.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub fn new(row: i32, col: i32) -> Self {
+        Point { row, col }
+    }
+
+    pub fn step(&self, dir: Direction) -> Point {
+        let (drow, dcol) = dir.delta();
+        Point::new(self.row + drow, self.col + dcol)
+    }
+}
+
+/// Cells at arbitrary signed coordinates, with bounds tracked as cells are set.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.row.min(point.row), min.col.min(point.col)),
+                Point::new(max.row.max(point.row), max.col.max(point.col)),
+            ),
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> {
+        self.cells.iter()
+    }
+
+    /// The top left and bottom right corners of every cell set so far.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Copies the bounded area into a dense grid, filling unset cells.
+    pub fn to_grid(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        match self.bounds {
+            Some((min, max)) => self.view(min, max, fill),
+            None => Grid::new(0, 0, fill),
+        }
+    }
+
+    /// Copies the area from `min` to `max` inclusive into a dense grid, filling unset cells.
+    pub fn view(&self, min: Point, max: Point, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = (max.col - min.col + 1).max(0) as usize;
+        let height = (max.row - min.row + 1).max(0) as usize;
+        let mut grid = Grid::new(width, height, fill);
+        let inside = |p: &Point| {
+            (min.row..=max.row).contains(&p.row) && (min.col..=max.col).contains(&p.col)
+        };
+        for (point, value) in self.cells.iter().filter(|(p, _)| inside(p)) {
+            let pos = Pos::new(
+                (point.row - min.row) as usize,
+                (point.col - min.col) as usize,
+            );
+            grid[pos] = value.clone();
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Direction, Point, SparseGrid};
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Point::new(0, 0), 's');
+        grid.insert(Point::new(-2, 3), 'H');
+        grid.insert(Point::new(1, -1), '#');
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(1, 3))));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(Point::new(-2, 3)), Some(&'H'));
+    }
+
+    #[test]
+    fn renders() {
+        let mut grid = SparseGrid::new();
+        let mut point = Point::default();
+        grid.insert(point, 's');
+        for dir in [Direction::Right, Direction::Right, Direction::Up] {
+            point = point.step(dir);
+            grid.insert(point, '#');
+        }
+        assert_eq!(grid.to_grid('.').to_string(), "..#\ns##");
+
+        let view = grid.view(Point::new(-2, 1), Point::new(0, 3), '.');
+        assert_eq!(view.to_string(), "...\n.#.\n##.");
+    }
+}